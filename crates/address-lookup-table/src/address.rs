@@ -0,0 +1,37 @@
+use nostd_entrypoint_invoke::pda::find_program_address;
+use solana_program::{clock::Slot, program_error::ProgramError, pubkey::Pubkey};
+
+/// Derive the address of the lookup table created by an authority at a
+/// recent slot, together with its bump seed.
+#[inline(always)]
+pub fn derive_lookup_table_address(
+    authority: &Pubkey,
+    recent_slot: Slot,
+) -> Result<(Pubkey, u8), ProgramError> {
+    find_program_address(
+        &[authority.as_ref(), &recent_slot.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::address_lookup_table::instruction;
+
+    use super::*;
+
+    #[test]
+    fn derive_lookup_table_address_matches_solana_program() {
+        let authority = Pubkey::new_unique();
+
+        for recent_slot in [0, 1, 42, Slot::MAX] {
+            assert_eq!(
+                derive_lookup_table_address(&authority, recent_slot),
+                Ok(instruction::derive_lookup_table_address(
+                    &authority,
+                    recent_slot
+                ))
+            );
+        }
+    }
+}
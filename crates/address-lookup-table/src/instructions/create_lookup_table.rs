@@ -0,0 +1,105 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{clock::Slot, entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Create an address lookup table.
+///
+/// The lookup table address is derived from the authority and the recent
+/// slot, see [`crate::address::derive_lookup_table_address`].
+///
+/// ### Accounts:
+///   0. `[WRITE]` Uninitialized address lookup table account
+///   1. `[SIGNER]` Account used to derive and control the new address lookup table
+///   2. `[WRITE, SIGNER]` Account that will fund the new address lookup table
+///   3. `[]` System program for CPI
+pub struct CreateLookupTable<'a> {
+    /// Uninitialized address lookup table account.
+    pub lookup_table: &'a NoStdAccountInfo,
+
+    /// Authority of the new address lookup table.
+    ///
+    /// Since v1.11 the authority does not need to sign, but it is still
+    /// passed as a signer for compatibility.
+    pub authority: &'a NoStdAccountInfo,
+
+    /// Account funding the new address lookup table.
+    pub payer: &'a NoStdAccountInfo,
+
+    /// System program.
+    pub system_program: &'a NoStdAccountInfo,
+
+    /// Recent slot used to derive the address lookup table address.
+    pub recent_slot: Slot,
+
+    /// Bump seed of the address lookup table address.
+    pub bump_seed: u8,
+}
+
+impl<'a> CreateLookupTable<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 4,
+            data: instruction_data.as_ptr(),
+            data_len: 13,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(
+            &instruction,
+            &[
+                self.lookup_table,
+                self.authority,
+                self.payer,
+                self.system_program,
+            ],
+            signers,
+        )
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 4] {
+        [
+            self.lookup_table.to_meta_c(),
+            self.authority.to_meta_c_signer(),
+            self.payer.to_meta_c_signer(),
+            self.system_program.to_meta_c(),
+        ]
+    }
+
+    /// Instruction data, as serialized by
+    /// `ProgramInstruction::CreateLookupTable`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 13] {
+        // - [0..4  ]: instruction discriminator
+        // - [4..12 ]: recent slot
+        // - [12    ]: bump seed
+        let mut instruction_data = [0; 13];
+        instruction_data[4..12].copy_from_slice(&self.recent_slot.to_le_bytes());
+        instruction_data[12] = self.bump_seed;
+        instruction_data
+    }
+}
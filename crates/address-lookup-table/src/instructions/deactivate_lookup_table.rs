@@ -0,0 +1,69 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Deactivate an address lookup table, making it unusable and eligible
+/// for closure after a short period of time.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Address lookup table account
+///   1. `[SIGNER]` Current authority
+pub struct DeactivateLookupTable<'a> {
+    /// Address lookup table account.
+    pub lookup_table: &'a NoStdAccountInfo,
+
+    /// Current authority.
+    pub authority: &'a NoStdAccountInfo,
+}
+
+impl<'a> DeactivateLookupTable<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 2,
+            data: instruction_data.as_ptr(),
+            data_len: 4,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.lookup_table, self.authority], signers)
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 2] {
+        [
+            self.lookup_table.to_meta_c(),
+            self.authority.to_meta_c_signer(),
+        ]
+    }
+
+    /// Instruction data, as serialized by `ProgramInstruction::DeactivateLookupTable`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 4] {
+        // - [0..4]: instruction discriminator
+        [3, 0, 0, 0]
+    }
+}
@@ -0,0 +1,142 @@
+use nostd_entrypoint_invoke::{invoke_signed_slice, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+/// Maximum number of addresses in a single `ExtendLookupTable` instruction.
+///
+/// This bounds the instruction data buffer kept on the stack; larger
+/// extensions can be split across several instructions.
+pub const MAX_NEW_ADDRESSES: usize = 32;
+
+/// Extend an address lookup table with new addresses.
+///
+/// A payer and the system program are required when the lookup table needs
+/// more lamports to stay rent exempt after being extended.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Address lookup table account
+///   1. `[SIGNER]` Current authority
+///   2. `[WRITE, SIGNER]` (optional) Account that will fund the table reallocation
+///   3. `[]` (optional) System program for CPI
+pub struct ExtendLookupTable<'a, 'b> {
+    /// Address lookup table account.
+    pub lookup_table: &'a NoStdAccountInfo,
+
+    /// Current authority.
+    pub authority: &'a NoStdAccountInfo,
+
+    /// Account funding the table reallocation, together with the system
+    /// program.
+    pub payer: Option<(&'a NoStdAccountInfo, &'a NoStdAccountInfo)>,
+
+    /// Addresses to append to the lookup table.
+    pub new_addresses: &'b [Pubkey],
+}
+
+impl<'a, 'b> ExtendLookupTable<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let (account_metas, accounts_len) = self.account_metas();
+        let (instruction_data, data_len) = self.instruction_data()?;
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: accounts_len as u64,
+            data: instruction_data.as_ptr(),
+            data_len: data_len as u64,
+            program_id: &crate::ID,
+        };
+
+        match self.payer {
+            Some((payer, system_program)) => invoke_signed_slice::<4>(
+                &instruction,
+                &[self.lookup_table, self.authority, payer, system_program],
+                signers,
+            ),
+            None => invoke_signed_slice::<4>(
+                &instruction,
+                &[self.lookup_table, self.authority],
+                signers,
+            ),
+        }
+    }
+
+    /// Account metadata of the instruction.
+    ///
+    /// Returns the metadata buffer together with the number of accounts in
+    /// use, which is 2 without a payer.
+    #[inline(always)]
+    pub fn account_metas(&self) -> ([AccountMetaC; 4], usize) {
+        let lookup_table = self.lookup_table.to_meta_c();
+        let authority = self.authority.to_meta_c_signer();
+
+        match self.payer {
+            Some((payer, system_program)) => (
+                [
+                    lookup_table,
+                    authority,
+                    payer.to_meta_c_signer(),
+                    system_program.to_meta_c(),
+                ],
+                4,
+            ),
+            // the last two metas only fill the buffer and are ignored, since
+            // only 2 accounts are in use
+            None => (
+                [
+                    lookup_table,
+                    authority,
+                    self.lookup_table.to_meta_c(),
+                    self.authority.to_meta_c_signer(),
+                ],
+                2,
+            ),
+        }
+    }
+
+    /// Instruction data, as serialized by
+    /// `ProgramInstruction::ExtendLookupTable`.
+    ///
+    /// Returns the data buffer together with the number of bytes in use, or
+    /// an error if there are more than [`MAX_NEW_ADDRESSES`] addresses.
+    #[inline(always)]
+    pub fn instruction_data(
+        &self,
+    ) -> Result<([u8; 12 + 32 * MAX_NEW_ADDRESSES], usize), ProgramError> {
+        if self.new_addresses.len() > MAX_NEW_ADDRESSES {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // - [0..4 ]: instruction discriminator
+        // - [4..12]: number of addresses
+        // - [12.. ]: addresses (32 bytes each)
+        let mut instruction_data = [0; 12 + 32 * MAX_NEW_ADDRESSES];
+        instruction_data[0] = 2;
+        instruction_data[4..12].copy_from_slice(&(self.new_addresses.len() as u64).to_le_bytes());
+
+        for (index, address) in self.new_addresses.iter().enumerate() {
+            let offset = 12 + 32 * index;
+            instruction_data[offset..offset + 32].copy_from_slice(address.as_ref());
+        }
+
+        Ok((instruction_data, 12 + 32 * self.new_addresses.len()))
+    }
+}
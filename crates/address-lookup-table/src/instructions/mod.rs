@@ -0,0 +1,204 @@
+mod close_lookup_table;
+mod create_lookup_table;
+mod deactivate_lookup_table;
+mod extend_lookup_table;
+mod freeze_lookup_table;
+
+pub use close_lookup_table::*;
+pub use create_lookup_table::*;
+pub use deactivate_lookup_table::*;
+pub use extend_lookup_table::*;
+pub use freeze_lookup_table::*;
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::{self, MockAccount};
+    use solana_program::{
+        address_lookup_table::instruction, entrypoint::ProgramResult, instruction::Instruction,
+        program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::address::derive_lookup_table_address;
+
+    /// Accounts of the lookup table instructions.
+    struct Accounts {
+        lookup_table: MockAccount,
+        authority: MockAccount,
+        payer: MockAccount,
+        system_program: MockAccount,
+    }
+
+    impl Accounts {
+        fn new(lookup_table: Pubkey, authority: Pubkey) -> Self {
+            Self {
+                lookup_table: MockAccount::new(lookup_table, crate::ID, 1, &[]).writable(true),
+                authority: MockAccount::new(authority, system_program::ID, 1, &[]).signer(true),
+                payer: MockAccount::new(Pubkey::new_unique(), system_program::ID, 1, &[])
+                    .writable(true)
+                    .signer(true),
+                system_program: MockAccount::new(system_program::ID, Pubkey::default(), 1, &[]),
+            }
+        }
+    }
+
+    /// Invoke a builder and return the instruction it invoked.
+    fn invoked(invoke: impl FnOnce() -> ProgramResult) -> Instruction {
+        mock::clear_invoked_instructions();
+        invoke().unwrap();
+
+        let mut invoked = mock::take_invoked_instructions();
+        assert_eq!(invoked.len(), 1);
+        invoked.remove(0).instruction
+    }
+
+    #[test]
+    fn create_lookup_table_matches_solana_program() {
+        let authority = Pubkey::new_unique();
+        let recent_slot = 123_456;
+        let (lookup_table, bump_seed) =
+            derive_lookup_table_address(&authority, recent_slot).unwrap();
+        let accounts = Accounts::new(lookup_table, authority);
+
+        let create = invoked(|| {
+            CreateLookupTable {
+                lookup_table: accounts.lookup_table.info(),
+                authority: accounts.authority.info(),
+                payer: accounts.payer.info(),
+                system_program: accounts.system_program.info(),
+                recent_slot,
+                bump_seed,
+            }
+            .invoke()
+        });
+
+        // the authority is passed as a signer, like the signed variant
+        let (expected, expected_address) =
+            instruction::create_lookup_table_signed(authority, accounts.payer.key(), recent_slot);
+        assert_eq!(expected_address, lookup_table);
+        assert_eq!(create, expected);
+
+        let (unsigned, _) =
+            instruction::create_lookup_table(authority, accounts.payer.key(), recent_slot);
+        assert_eq!(create.data, unsigned.data);
+    }
+
+    #[test]
+    fn authority_instructions_match_solana_program() {
+        let accounts = Accounts::new(Pubkey::new_unique(), Pubkey::new_unique());
+        let (lookup_table, authority) = (accounts.lookup_table.key(), accounts.authority.key());
+        let recipient =
+            MockAccount::new(Pubkey::new_unique(), system_program::ID, 1, &[]).writable(true);
+
+        let freeze = invoked(|| {
+            FreezeLookupTable {
+                lookup_table: accounts.lookup_table.info(),
+                authority: accounts.authority.info(),
+            }
+            .invoke()
+        });
+        assert_eq!(
+            freeze,
+            instruction::freeze_lookup_table(lookup_table, authority)
+        );
+
+        let deactivate = invoked(|| {
+            DeactivateLookupTable {
+                lookup_table: accounts.lookup_table.info(),
+                authority: accounts.authority.info(),
+            }
+            .invoke()
+        });
+        assert_eq!(
+            deactivate,
+            instruction::deactivate_lookup_table(lookup_table, authority)
+        );
+
+        let close = invoked(|| {
+            CloseLookupTable {
+                lookup_table: accounts.lookup_table.info(),
+                authority: accounts.authority.info(),
+                recipient: recipient.info(),
+            }
+            .invoke()
+        });
+        assert_eq!(
+            close,
+            instruction::close_lookup_table(lookup_table, authority, recipient.key())
+        );
+    }
+
+    #[test]
+    fn extend_lookup_table_matches_solana_program() {
+        let accounts = Accounts::new(Pubkey::new_unique(), Pubkey::new_unique());
+        let (lookup_table, authority) = (accounts.lookup_table.key(), accounts.authority.key());
+        let new_addresses = (0..MAX_NEW_ADDRESSES)
+            .map(|_| Pubkey::new_unique())
+            .collect::<Vec<_>>();
+
+        for len in [0, 1, MAX_NEW_ADDRESSES] {
+            let new_addresses = &new_addresses[..len];
+
+            let extend = invoked(|| {
+                ExtendLookupTable {
+                    lookup_table: accounts.lookup_table.info(),
+                    authority: accounts.authority.info(),
+                    payer: None,
+                    new_addresses,
+                }
+                .invoke()
+            });
+            assert_eq!(
+                extend,
+                instruction::extend_lookup_table(
+                    lookup_table,
+                    authority,
+                    None,
+                    new_addresses.to_vec()
+                )
+            );
+
+            let extend_with_payer = invoked(|| {
+                ExtendLookupTable {
+                    lookup_table: accounts.lookup_table.info(),
+                    authority: accounts.authority.info(),
+                    payer: Some((accounts.payer.info(), accounts.system_program.info())),
+                    new_addresses,
+                }
+                .invoke()
+            });
+            assert_eq!(
+                extend_with_payer,
+                instruction::extend_lookup_table(
+                    lookup_table,
+                    authority,
+                    Some(accounts.payer.key()),
+                    new_addresses.to_vec()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn extend_lookup_table_bounds_the_new_addresses() {
+        let accounts = Accounts::new(Pubkey::new_unique(), Pubkey::new_unique());
+        let new_addresses = [Pubkey::new_unique(); MAX_NEW_ADDRESSES + 1];
+
+        mock::clear_invoked_instructions();
+
+        assert_eq!(
+            ExtendLookupTable {
+                lookup_table: accounts.lookup_table.info(),
+                authority: accounts.authority.info(),
+                payer: None,
+                new_addresses: &new_addresses,
+            }
+            .invoke(),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert!(mock::take_invoked_instructions().is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+#![no_std]
+
+use solana_nostd_entrypoint::solana_program::declare_id;
+
+pub mod address;
+pub mod instructions;
+pub mod state;
+
+declare_id!("AddressLookupTab1e1111111111111111111111111");
@@ -0,0 +1,275 @@
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{clock::Slot, program_error::ProgramError, pubkey::Pubkey};
+
+/// Maximum number of addresses that a lookup table can hold.
+pub const LOOKUP_TABLE_MAX_ADDRESSES: usize = 256;
+
+/// Zero-copy view of the metadata of an address lookup table.
+///
+/// The layout matches the 56 bytes of a bincode-serialized
+/// `ProgramState::LookupTable`, which precede the address list.
+#[repr(C)]
+pub struct LookupTableMeta {
+    /// `ProgramState` discriminator.
+    state: [u8; 4],
+
+    /// Slot at which the table was deactivated, or `Slot::MAX` if it is
+    /// active.
+    deactivation_slot: [u8; 8],
+
+    /// Slot at which the table was last extended.
+    last_extended_slot: [u8; 8],
+
+    /// Number of addresses before the last extension.
+    last_extended_slot_start_index: u8,
+
+    /// `Option` tag of the authority.
+    authority_tag: u8,
+
+    /// Authority of the table, unset once the table is frozen.
+    authority: Pubkey,
+
+    /// Padding to keep the address list aligned.
+    _padding: [u8; 2],
+}
+
+const _: () = assert!(core::mem::size_of::<LookupTableMeta>() == LookupTableMeta::LEN);
+const _: () = assert!(core::mem::align_of::<LookupTableMeta>() == 1);
+
+impl LookupTableMeta {
+    /// Length of the lookup table metadata.
+    pub const LEN: usize = 56;
+
+    /// Slot at which the table was deactivated, or `None` if it is active.
+    #[inline(always)]
+    pub fn deactivation_slot(&self) -> Option<Slot> {
+        let slot = Slot::from_le_bytes(self.deactivation_slot);
+        (slot != Slot::MAX).then_some(slot)
+    }
+
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.deactivation_slot().is_none()
+    }
+
+    #[inline(always)]
+    pub fn last_extended_slot(&self) -> Slot {
+        Slot::from_le_bytes(self.last_extended_slot)
+    }
+
+    #[inline(always)]
+    pub fn last_extended_slot_start_index(&self) -> u8 {
+        self.last_extended_slot_start_index
+    }
+
+    /// Authority of the table, or `None` if the table is frozen.
+    #[inline(always)]
+    pub fn authority(&self) -> Option<&Pubkey> {
+        (self.authority_tag != 0).then_some(&self.authority)
+    }
+
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        self.authority().is_none()
+    }
+}
+
+/// Zero-copy view of an address lookup table account.
+pub struct AddressLookupTable<'a> {
+    /// Metadata of the table.
+    meta: &'a LookupTableMeta,
+
+    /// Addresses stored in the table.
+    addresses: &'a [Pubkey],
+}
+
+impl<'a> AddressLookupTable<'a> {
+    /// Borrow the data of a lookup table account owned by the address lookup
+    /// table program, to be viewed with [`AddressLookupTable::from_bytes`].
+    #[inline(always)]
+    pub fn borrow_data(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = [u8]> + '_, ProgramError> {
+        if *account_info.owner() != crate::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        account_info
+            .try_borrow_data()
+            .ok_or(ProgramError::AccountBorrowFailed)
+    }
+
+    /// View the bytes of a lookup table account.
+    ///
+    /// Returns `UninitializedAccount` if the table is not initialized.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if data.len() < LookupTableMeta::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let meta = unsafe { &*(data.as_ptr() as *const LookupTableMeta) };
+
+        match u32::from_le_bytes(meta.state) {
+            0 => return Err(ProgramError::UninitializedAccount),
+            1 => {}
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+
+        if meta.authority_tag > 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let addresses = &data[LookupTableMeta::LEN..];
+
+        if addresses.len() % 32 != 0 || addresses.len() / 32 > LOOKUP_TABLE_MAX_ADDRESSES {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let addresses = unsafe {
+            core::slice::from_raw_parts(addresses.as_ptr() as *const Pubkey, addresses.len() / 32)
+        };
+
+        Ok(Self { meta, addresses })
+    }
+
+    #[inline(always)]
+    pub fn meta(&self) -> &'a LookupTableMeta {
+        self.meta
+    }
+
+    /// Addresses stored in the table.
+    ///
+    /// Addresses appended at the last extended slot are included, although
+    /// they are not usable in transactions until the next slot.
+    #[inline(always)]
+    pub fn addresses(&self) -> &'a [Pubkey] {
+        self.addresses
+    }
+
+    /// Address at `index`, or `None` if there is no such address.
+    #[inline(always)]
+    pub fn get(&self, index: u8) -> Option<&'a Pubkey> {
+        self.addresses.get(index as usize)
+    }
+
+    /// Number of addresses that are usable at `current_slot`.
+    ///
+    /// Addresses appended at the current slot are only usable from the next
+    /// slot on.
+    #[inline(always)]
+    pub fn active_addresses_len(&self, current_slot: Slot) -> usize {
+        if current_slot > self.meta.last_extended_slot() {
+            self.addresses.len()
+        } else {
+            self.meta.last_extended_slot_start_index() as usize
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::{borrow::Cow, vec, vec::Vec};
+
+    use solana_program::address_lookup_table::state;
+
+    use super::*;
+
+    fn serialize(meta: state::LookupTableMeta, addresses: &[Pubkey]) -> Vec<u8> {
+        state::AddressLookupTable {
+            meta,
+            addresses: Cow::Borrowed(addresses),
+        }
+        .serialize_for_tests()
+        .unwrap()
+    }
+
+    #[test]
+    fn reads_an_active_table() {
+        let authority = Pubkey::new_unique();
+        let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let mut meta = state::LookupTableMeta::new(authority);
+        meta.last_extended_slot = 7;
+        meta.last_extended_slot_start_index = 1;
+
+        let data = serialize(meta, &addresses);
+        let table = AddressLookupTable::from_bytes(&data).unwrap();
+
+        assert_eq!(table.meta().deactivation_slot(), None);
+        assert!(table.meta().is_active());
+        assert_eq!(table.meta().last_extended_slot(), 7);
+        assert_eq!(table.meta().last_extended_slot_start_index(), 1);
+        assert_eq!(table.meta().authority(), Some(&authority));
+        assert!(!table.meta().is_frozen());
+        assert_eq!(table.addresses(), &addresses);
+        assert_eq!(table.get(1), Some(&addresses[1]));
+        assert_eq!(table.get(2), None);
+        assert_eq!(table.active_addresses_len(7), 1);
+        assert_eq!(table.active_addresses_len(8), 2);
+    }
+
+    #[test]
+    fn reads_a_frozen_deactivated_table() {
+        let authority = Pubkey::new_unique();
+        let addresses = [Pubkey::new_unique()];
+
+        // freezing rewrites the metadata over the previous authority bytes
+        let mut data = serialize(state::LookupTableMeta::new(authority), &addresses);
+
+        let meta = state::LookupTableMeta {
+            deactivation_slot: 9,
+            authority: None,
+            ..state::LookupTableMeta::default()
+        };
+        state::AddressLookupTable::overwrite_meta_data(&mut data, meta).unwrap();
+
+        assert_eq!(data[21], 0);
+
+        let table = AddressLookupTable::from_bytes(&data).unwrap();
+
+        assert_eq!(table.meta().deactivation_slot(), Some(9));
+        assert!(!table.meta().is_active());
+        assert_eq!(table.meta().authority(), None);
+        assert!(table.meta().is_frozen());
+        assert_eq!(table.addresses(), &addresses);
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let data = serialize(state::LookupTableMeta::new(Pubkey::new_unique()), &[]);
+
+        assert_eq!(
+            AddressLookupTable::from_bytes(&data[..LookupTableMeta::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            AddressLookupTable::from_bytes(&[0; LookupTableMeta::LEN]).err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+
+        let mut unaligned = data.clone();
+        unaligned.push(0);
+        assert_eq!(
+            AddressLookupTable::from_bytes(&unaligned).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let mut bad_tag = data.clone();
+        bad_tag[21] = 2;
+        assert_eq!(
+            AddressLookupTable::from_bytes(&bad_tag).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let mut too_long = data;
+        too_long.extend(vec![0; 32 * (LOOKUP_TABLE_MAX_ADDRESSES + 1)]);
+        assert_eq!(
+            AddressLookupTable::from_bytes(&too_long).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use nostd_entrypoint_invoke::pda::find_program_address;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+/// Derive the associated token account address of a wallet for a mint of
+/// the Token program.
+#[inline(always)]
+pub fn get_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    get_associated_token_address_with_program_id(wallet, mint, &nostd_token_program::ID)
+}
+
+/// Derive the associated token account address of a wallet for a mint of
+/// the given token program, such as Token or Token-2022.
+#[inline(always)]
+pub fn get_associated_token_address_with_program_id(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    find_associated_token_address(wallet, mint, token_program_id).map(|(address, _)| address)
+}
+
+/// Derive the associated token account address of a wallet for a mint of
+/// the given token program, together with its bump seed.
+#[inline(always)]
+pub fn find_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Result<(Pubkey, u8), ProgramError> {
+    find_program_address(
+        &[wallet.as_ref(), token_program_id.as_ref(), mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses_match_spl_associated_token_account() {
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        assert_eq!(
+            get_associated_token_address(&wallet, &mint),
+            Ok(spl_associated_token_account::get_associated_token_address(
+                &wallet, &mint
+            ))
+        );
+
+        for token_program_id in [nostd_token_program::ID, spl_token_2022::ID] {
+            assert_eq!(
+                get_associated_token_address_with_program_id(&wallet, &mint, &token_program_id),
+                Ok(
+                    spl_associated_token_account::get_associated_token_address_with_program_id(
+                        &wallet,
+                        &mint,
+                        &token_program_id
+                    )
+                )
+            );
+        }
+    }
+}
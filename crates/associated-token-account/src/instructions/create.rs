@@ -0,0 +1,112 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Creates an associated token account for the given wallet address and
+/// token mint.
+///
+/// Returns an error if the account exists.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE]` Associated token account
+///   2. `[]` Wallet address of the associated token account
+///   3. `[]` Token mint
+///   4. `[]` System program
+///   5. `[]` Token program
+pub struct Create<'a> {
+    /// Funding account (must be a system account).
+    pub funding_account: &'a NoStdAccountInfo,
+
+    /// Associated token account.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Wallet address of the associated token account.
+    pub wallet: &'a NoStdAccountInfo,
+
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// System program.
+    pub system_program: &'a NoStdAccountInfo,
+
+    /// Token program.
+    pub token_program: &'a NoStdAccountInfo,
+}
+
+impl<'a> Create<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        self.invoke_with_data(self.instruction_data(), signers)
+    }
+
+    /// Invoke the instruction with the data of `Create` or
+    /// `CreateIdempotent`, which share their accounts.
+    pub(crate) fn invoke_with_data(
+        &self,
+        instruction_data: [u8; 1],
+        signers: PdaSigners,
+    ) -> ProgramResult {
+        let account_metas = self.account_metas();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 6,
+            data: instruction_data.as_ptr(),
+            data_len: 1,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(
+            &instruction,
+            &[
+                self.funding_account,
+                self.account,
+                self.wallet,
+                self.mint,
+                self.system_program,
+                self.token_program,
+            ],
+            signers,
+        )
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 6] {
+        [
+            self.funding_account.to_meta_c_signer(),
+            self.account.to_meta_c(),
+            self.wallet.to_meta_c(),
+            self.mint.to_meta_c(),
+            self.system_program.to_meta_c(),
+            self.token_program.to_meta_c(),
+        ]
+    }
+
+    /// Instruction data, as serialized by
+    /// `AssociatedTokenAccountInstruction::Create`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [0]
+    }
+}
@@ -0,0 +1,90 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use super::Create;
+
+/// Creates an associated token account for the given wallet address and
+/// token mint, if it doesn't already exist.
+///
+/// Returns an error if the account exists, but with a different owner.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE]` Associated token account
+///   2. `[]` Wallet address of the associated token account
+///   3. `[]` Token mint
+///   4. `[]` System program
+///   5. `[]` Token program
+pub struct CreateIdempotent<'a> {
+    /// Funding account (must be a system account).
+    pub funding_account: &'a NoStdAccountInfo,
+
+    /// Associated token account.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Wallet address of the associated token account.
+    pub wallet: &'a NoStdAccountInfo,
+
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// System program.
+    pub system_program: &'a NoStdAccountInfo,
+
+    /// Token program.
+    pub token_program: &'a NoStdAccountInfo,
+}
+
+impl<'a> CreateIdempotent<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        self.create()
+            .invoke_with_data(self.instruction_data(), signers)
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 6] {
+        self.create().account_metas()
+    }
+
+    /// Instruction data, as serialized by
+    /// `AssociatedTokenAccountInstruction::CreateIdempotent`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [1]
+    }
+
+    /// The `Create` instruction over the same accounts.
+    #[inline(always)]
+    fn create(&self) -> Create<'a> {
+        Create {
+            funding_account: self.funding_account,
+            account: self.account,
+            wallet: self.wallet,
+            mint: self.mint,
+            system_program: self.system_program,
+            token_program: self.token_program,
+        }
+    }
+}
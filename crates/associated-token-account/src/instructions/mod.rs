@@ -0,0 +1,141 @@
+mod create;
+mod create_idempotent;
+mod recover_nested;
+
+pub use create::*;
+pub use create_idempotent::*;
+pub use recover_nested::*;
+
+#[cfg(test)]
+mod tests {
+    use nostd_entrypoint_invoke::mock::{self, MockAccount};
+    use solana_program::{pubkey::Pubkey, system_program};
+    use spl_associated_token_account::instruction::{
+        create_associated_token_account, create_associated_token_account_idempotent, recover_nested,
+    };
+
+    use super::*;
+    use crate::address::get_associated_token_address_with_program_id;
+
+    #[test]
+    fn create_instructions_match_spl_associated_token_account() {
+        let token_program_id = spl_token_2022::ID;
+
+        let funding_account = MockAccount::new(Pubkey::new_unique(), system_program::ID, 1, &[])
+            .writable(true)
+            .signer(true);
+        let wallet = MockAccount::new(Pubkey::new_unique(), system_program::ID, 0, &[]);
+        let mint = MockAccount::new(Pubkey::new_unique(), token_program_id, 0, &[]);
+        let account = MockAccount::new(
+            get_associated_token_address_with_program_id(
+                &wallet.key(),
+                &mint.key(),
+                &token_program_id,
+            )
+            .unwrap(),
+            system_program::ID,
+            0,
+            &[],
+        )
+        .writable(true);
+        let system_program = MockAccount::new(system_program::ID, Pubkey::default(), 0, &[]);
+        let token_program = MockAccount::new(token_program_id, Pubkey::default(), 0, &[]);
+
+        mock::clear_invoked_instructions();
+
+        Create {
+            funding_account: funding_account.info(),
+            account: account.info(),
+            wallet: wallet.info(),
+            mint: mint.info(),
+            system_program: system_program.info(),
+            token_program: token_program.info(),
+        }
+        .invoke()
+        .unwrap();
+
+        CreateIdempotent {
+            funding_account: funding_account.info(),
+            account: account.info(),
+            wallet: wallet.info(),
+            mint: mint.info(),
+            system_program: system_program.info(),
+            token_program: token_program.info(),
+        }
+        .invoke()
+        .unwrap();
+
+        let invoked = mock::take_invoked_instructions();
+        assert_eq!(invoked.len(), 2);
+        assert_eq!(
+            invoked[0].instruction,
+            create_associated_token_account(
+                &funding_account.key(),
+                &wallet.key(),
+                &mint.key(),
+                &token_program_id
+            )
+        );
+        assert_eq!(
+            invoked[1].instruction,
+            create_associated_token_account_idempotent(
+                &funding_account.key(),
+                &wallet.key(),
+                &mint.key(),
+                &token_program_id
+            )
+        );
+    }
+
+    #[test]
+    fn recover_nested_matches_spl_associated_token_account() {
+        let token_program_id = spl_token_2022::ID;
+        let ata = |wallet: &Pubkey, mint: &Pubkey| {
+            get_associated_token_address_with_program_id(wallet, mint, &token_program_id).unwrap()
+        };
+        let token_account = |key: Pubkey| MockAccount::new(key, token_program_id, 1, &[]);
+
+        let wallet = MockAccount::new(Pubkey::new_unique(), system_program::ID, 1, &[])
+            .writable(true)
+            .signer(true);
+        let owner_mint = token_account(Pubkey::new_unique());
+        let nested_mint = token_account(Pubkey::new_unique());
+        let owner_account = token_account(ata(&wallet.key(), &owner_mint.key()));
+        let destination_account =
+            token_account(ata(&wallet.key(), &nested_mint.key())).writable(true);
+        let nested_account =
+            token_account(ata(&owner_account.key(), &nested_mint.key())).writable(true);
+        let token_program = MockAccount::new(token_program_id, Pubkey::default(), 0, &[]);
+
+        mock::clear_invoked_instructions();
+
+        RecoverNested {
+            nested_account: nested_account.info(),
+            nested_mint: nested_mint.info(),
+            destination_account: destination_account.info(),
+            owner_account: owner_account.info(),
+            owner_mint: owner_mint.info(),
+            wallet: wallet.info(),
+            token_program: token_program.info(),
+        }
+        .invoke()
+        .unwrap();
+
+        let invoked = mock::take_invoked_instructions();
+        assert_eq!(invoked.len(), 1);
+        assert_eq!(
+            invoked[0].instruction,
+            recover_nested(
+                &wallet.key(),
+                &owner_mint.key(),
+                &nested_mint.key(),
+                &token_program_id
+            )
+        );
+
+        // the wallet receives the lamports of the nested account
+        let wallet_meta = &invoked[0].instruction.accounts[5];
+        assert_eq!(wallet_meta.pubkey, wallet.key());
+        assert!(wallet_meta.is_writable && wallet_meta.is_signer);
+    }
+}
@@ -0,0 +1,110 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Transfers from and closes a nested associated token account: an
+/// associated token account owned by an associated token account.
+///
+/// The tokens are moved from the nested associated token account to the
+/// wallet's associated token account, and the nested account lamports are
+/// moved to the wallet.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Nested associated token account, owned by `3`
+///   1. `[]` Token mint of the nested associated token account
+///   2. `[WRITE]` Wallet's associated token account
+///   3. `[]` Owner associated token account, owned by `5`
+///   4. `[]` Token mint of the owner associated token account
+///   5. `[WRITE, SIGNER]` Wallet address
+///   6. `[]` Token program
+pub struct RecoverNested<'a> {
+    /// Nested associated token account.
+    pub nested_account: &'a NoStdAccountInfo,
+
+    /// Token mint of the nested associated token account.
+    pub nested_mint: &'a NoStdAccountInfo,
+
+    /// Wallet's associated token account.
+    pub destination_account: &'a NoStdAccountInfo,
+
+    /// Owner associated token account.
+    pub owner_account: &'a NoStdAccountInfo,
+
+    /// Token mint of the owner associated token account.
+    pub owner_mint: &'a NoStdAccountInfo,
+
+    /// Wallet address.
+    pub wallet: &'a NoStdAccountInfo,
+
+    /// Token program.
+    pub token_program: &'a NoStdAccountInfo,
+}
+
+impl<'a> RecoverNested<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 7,
+            data: instruction_data.as_ptr(),
+            data_len: 1,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(
+            &instruction,
+            &[
+                self.nested_account,
+                self.nested_mint,
+                self.destination_account,
+                self.owner_account,
+                self.owner_mint,
+                self.wallet,
+                self.token_program,
+            ],
+            signers,
+        )
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 7] {
+        [
+            self.nested_account.to_meta_c(),
+            self.nested_mint.to_meta_c(),
+            self.destination_account.to_meta_c(),
+            self.owner_account.to_meta_c(),
+            self.owner_mint.to_meta_c(),
+            self.wallet.to_meta_c_signer(),
+            self.token_program.to_meta_c(),
+        ]
+    }
+
+    /// Instruction data, as serialized by
+    /// `AssociatedTokenAccountInstruction::RecoverNested`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [2]
+    }
+}
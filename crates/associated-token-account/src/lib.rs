@@ -0,0 +1,8 @@
+#![no_std]
+
+use solana_nostd_entrypoint::solana_program::declare_id;
+
+pub mod address;
+pub mod instructions;
+
+declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
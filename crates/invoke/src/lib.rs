@@ -3,22 +3,82 @@
 use core::mem::MaybeUninit;
 
 use solana_nostd_entrypoint::{
-    solana_program::entrypoint::ProgramResult, AccountInfoC, InstructionC, NoStdAccountInfo,
+    solana_program::entrypoint::ProgramResult, AccountInfoC, AccountMetaC, InstructionC,
+    NoStdAccountInfo,
 };
-use solana_program::program_error::ProgramError;
+use solana_program::{program::MAX_RETURN_DATA, program_error::ProgramError, pubkey::Pubkey};
+
+#[cfg(all(feature = "mock", not(target_os = "solana")))]
+extern crate std;
+
+#[cfg(all(feature = "mock", not(target_os = "solana")))]
+pub mod mock;
+pub mod pda;
+pub mod reader;
+pub mod signer;
+pub mod state;
+
+pub use signer::{PdaSigners, Seed, Signer};
 
 #[inline(always)]
 pub fn invoke<const ACCOUNTS: usize>(
     instruction: &InstructionC,
     account_infos: &[&NoStdAccountInfo; ACCOUNTS],
 ) -> ProgramResult {
-    invoke_signed(instruction, account_infos, &[])
+    invoke_signed_with(instruction, account_infos, PdaSigners::NONE)
 }
 
+/// Invoke a cross-program instruction signed by the program derived
+/// addresses of `program_id` with the given seeds.
+///
+/// See [`invoke_signed_with`].
+///
+/// # Breaking change
+///
+/// `program_id` was added so that signer metas can be checked against the
+/// addresses derived from `signers_seeds` before invoking. Callers of the
+/// former `invoke_signed(instruction, accounts, signers_seeds)` pass the id
+/// of the program deriving the addresses, usually their own.
+#[inline(always)]
 pub fn invoke_signed<const ACCOUNTS: usize>(
     instruction: &InstructionC,
     accounts: &[&NoStdAccountInfo; ACCOUNTS],
+    program_id: &Pubkey,
     signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed_with(
+        instruction,
+        accounts,
+        PdaSigners::new(program_id, signers_seeds),
+    )
+}
+
+/// Invoke a cross-program instruction signed with C-ABI [`Signer`] seeds.
+///
+/// This function is like [`invoke_signed`] except that the signer seeds are
+/// passed to the runtime in the `SolSignerSeedsC` layout it expects.
+#[inline(always)]
+pub fn invoke_signed_with_signers<const ACCOUNTS: usize>(
+    instruction: &InstructionC,
+    accounts: &[&NoStdAccountInfo; ACCOUNTS],
+    program_id: &Pubkey,
+    signers: &[Signer],
+) -> ProgramResult {
+    invoke_signed_with(
+        instruction,
+        accounts,
+        PdaSigners::from_signers(program_id, signers),
+    )
+}
+
+/// Invoke a cross-program instruction signed by program derived addresses.
+///
+/// The metas of the instruction are matched to `accounts` by position and
+/// checked with the accounts, see [`check_account`].
+pub fn invoke_signed_with<const ACCOUNTS: usize>(
+    instruction: &InstructionC,
+    accounts: &[&NoStdAccountInfo; ACCOUNTS],
+    signers: PdaSigners,
 ) -> ProgramResult {
     if (instruction.accounts_len as usize) < ACCOUNTS {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -30,25 +90,137 @@ pub fn invoke_signed<const ACCOUNTS: usize>(
     let metas = unsafe { core::slice::from_raw_parts(instruction.accounts, ACCOUNTS) };
 
     for index in 0..ACCOUNTS {
-        let info = &accounts[index];
-        let meta = &metas[index];
+        let info = accounts[index];
+        check_account(info, &metas[index], &signers)?;
+        infos[index].write(info.to_info_c());
+    }
 
-        if *info.key() != unsafe { *meta.pubkey } {
-            return Err(ProgramError::InvalidArgument);
-        }
+    unsafe { invoke_unchecked(instruction, infos, signers) }
+}
 
-        if meta.is_writable {
-            let _ = info.try_borrow_mut_data();
-            let _ = info.try_borrow_mut_lamports();
-        } else {
-            let _ = info.try_borrow_data();
-            let _ = info.try_borrow_lamports();
-        }
+#[inline(always)]
+pub fn invoke_slice<const MAX_ACCOUNTS: usize>(
+    instruction: &InstructionC,
+    account_infos: &[&NoStdAccountInfo],
+) -> ProgramResult {
+    invoke_signed_slice::<MAX_ACCOUNTS>(instruction, account_infos, PdaSigners::NONE)
+}
+
+/// Invoke a cross-program instruction with a runtime-sized list of accounts.
+///
+/// This function is like [`invoke_signed_with`] except that the number of
+/// accounts is only known at runtime, e.g. when forwarding remaining
+/// accounts. The `MAX_ACCOUNTS` capacity bounds the stack buffer used for the
+/// C-ABI account infos; passing more accounts than that returns an error.
+pub fn invoke_signed_slice<const MAX_ACCOUNTS: usize>(
+    instruction: &InstructionC,
+    accounts: &[&NoStdAccountInfo],
+    signers: PdaSigners,
+) -> ProgramResult {
+    if accounts.len() > MAX_ACCOUNTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if (instruction.accounts_len as usize) < accounts.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
 
+    const UNINIT: MaybeUninit<AccountInfoC> = MaybeUninit::<AccountInfoC>::uninit();
+    let mut infos = [UNINIT; MAX_ACCOUNTS];
+
+    let metas = unsafe { core::slice::from_raw_parts(instruction.accounts, accounts.len()) };
+
+    for (index, (info, meta)) in accounts.iter().zip(metas.iter()).enumerate() {
+        check_account(info, meta, &signers)?;
         infos[index].write(info.to_info_c());
     }
 
-    invoke_unchecked(instruction, infos, signers_seeds)?;
+    unsafe { invoke_raw(instruction, &infos[..accounts.len()], signers) }
+}
+
+#[inline(always)]
+pub fn invoke_by_key<const ACCOUNTS: usize>(
+    instruction: &InstructionC,
+    account_infos: &[&NoStdAccountInfo; ACCOUNTS],
+) -> ProgramResult {
+    invoke_signed_by_key(instruction, account_infos, PdaSigners::NONE)
+}
+
+/// Invoke a cross-program instruction resolving account metas by key.
+///
+/// This function is like [`invoke_signed_with`] except that metas are not
+/// matched to accounts by position: each meta key must appear somewhere in
+/// `accounts`, as the runtime requires. Metas may therefore repeat a key or
+/// be ordered differently from the accounts, and accounts not referenced by
+/// any meta are passed through to the callee.
+pub fn invoke_signed_by_key<const ACCOUNTS: usize>(
+    instruction: &InstructionC,
+    accounts: &[&NoStdAccountInfo; ACCOUNTS],
+    signers: PdaSigners,
+) -> ProgramResult {
+    let metas = unsafe {
+        core::slice::from_raw_parts(instruction.accounts, instruction.accounts_len as usize)
+    };
+
+    for meta in metas {
+        let info = accounts
+            .iter()
+            .find(|info| *info.key() == unsafe { *meta.pubkey })
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        check_account(info, meta, &signers)?;
+    }
+
+    const UNINIT: MaybeUninit<AccountInfoC> = MaybeUninit::<AccountInfoC>::uninit();
+    let mut infos = [UNINIT; ACCOUNTS];
+
+    for (info_c, info) in infos.iter_mut().zip(accounts.iter()) {
+        info_c.write(info.to_info_c());
+    }
+
+    unsafe { invoke_unchecked(instruction, infos, signers) }
+}
+
+/// Check that an account can be passed for the given meta.
+///
+/// - The account key must be the meta key, or `NotEnoughAccountKeys` is
+///   returned.
+/// - A writable meta requires a writable account, or `InvalidArgument` is
+///   returned.
+/// - The account data and lamports must be borrowable as required by the
+///   meta, which also fails when a duplicate of the account is currently
+///   borrowed by the caller, or `AccountBorrowFailed` is returned.
+/// - A signer meta requires a signer account or one of the program derived
+///   addresses of `signers`, or `MissingRequiredSignature` is returned.
+#[inline(always)]
+fn check_account(
+    info: &NoStdAccountInfo,
+    meta: &AccountMetaC,
+    signers: &PdaSigners,
+) -> ProgramResult {
+    if *info.key() != unsafe { *meta.pubkey } {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if meta.is_writable {
+        if !info.is_writable() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        info.try_borrow_mut_data()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+        info.try_borrow_mut_lamports()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+    } else {
+        info.try_borrow_data()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+        info.try_borrow_lamports()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+    }
+
+    if meta.is_signer && !info.is_signer() && !signers.signs(info.key()) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
 
     Ok(())
 }
@@ -56,39 +228,136 @@ pub fn invoke_signed<const ACCOUNTS: usize>(
 /// Invoke a cross-program instruction with signatures but don't enforce Rust's
 /// aliasing rules.
 ///
-/// This function is like [`invoke_signed`] except that it does not check that
-/// [`RefCell`]s within [`AccountInfo`]s are properly borrowable as described in
-/// the documentation for that function. Those checks consume CPU cycles that
-/// this function avoids.
+/// This function is like [`invoke_signed_with`] except that it does not check
+/// that [`RefCell`]s within [`AccountInfo`]s are properly borrowable as
+/// described in the documentation for that function. Those checks consume CPU
+/// cycles that this function avoids.
 ///
 /// [`RefCell`]: std::cell::RefCell
 ///
 /// # Safety
 ///
-/// __This function is incorrectly missing an `unsafe` declaration.__
+/// Every element of `account_infos` must be initialized.
 ///
 /// If any of the writable accounts passed to the callee contain data that is
 /// borrowed within the calling program, and that data is written to by the
 /// callee, then Rust's aliasing rules will be violated and cause undefined
 /// behavior.
-pub fn invoke_unchecked<const ACCOUNTS: usize>(
+///
+/// # Breaking change
+///
+/// This function is now `unsafe`, as its former documentation required, and
+/// takes [`PdaSigners`] instead of `signers_seeds: &[&[&[u8]]]`. Former
+/// callers pass `PdaSigners::new(program_id, signers_seeds)`.
+pub unsafe fn invoke_unchecked<const ACCOUNTS: usize>(
     instruction: &InstructionC,
     account_infos: [MaybeUninit<AccountInfoC>; ACCOUNTS],
-    signers_seeds: &[&[&[u8]]],
+    signers: PdaSigners,
+) -> ProgramResult {
+    invoke_raw(instruction, &account_infos, signers)
+}
+
+/// Issue the `sol_invoke_signed_c` syscall.
+///
+/// A non-zero status returned by the syscall is decoded into a
+/// [`ProgramError`].
+///
+/// # Safety
+///
+/// Every element of `account_infos` must be initialized, and the aliasing
+/// requirements of [`invoke_unchecked`] apply.
+unsafe fn invoke_raw(
+    instruction: &InstructionC,
+    account_infos: &[MaybeUninit<AccountInfoC>],
+    signers: PdaSigners,
 ) -> ProgramResult {
     #[cfg(target_os = "solana")]
-    unsafe {
-        solana_program::syscalls::sol_invoke_signed_c(
+    {
+        let result = solana_program::syscalls::sol_invoke_signed_c(
             instruction as *const InstructionC as *const u8,
             account_infos.as_ptr() as *const u8,
             account_infos.len() as u64,
-            signers_seeds.as_ptr() as *const u8,
-            signers_seeds.len() as u64,
+            signers.as_ptr(),
+            signers.len() as u64,
         );
+
+        if result != solana_program::entrypoint::SUCCESS {
+            return Err(ProgramError::from(result));
+        }
     }
 
-    #[cfg(not(target_os = "solana"))]
-    core::hint::black_box(&(&instruction, &account_infos, &signers_seeds));
+    #[cfg(all(feature = "mock", not(target_os = "solana")))]
+    mock::invoke(
+        instruction,
+        account_infos,
+        signers.program_id(),
+        signers.to_vec(),
+    )?;
+
+    #[cfg(all(not(feature = "mock"), not(target_os = "solana")))]
+    core::hint::black_box(&(&instruction, &account_infos, &signers));
+
+    Ok(())
+}
+
+/// Get the return data set by the last invoked program.
+///
+/// The return data is copied into `data`, truncated to its length, and the
+/// program id that set it is returned together with the number of bytes
+/// copied. Returns `None` if there is no return data.
+#[inline(always)]
+pub fn get_return_data(data: &mut [u8]) -> Option<(Pubkey, usize)> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut program_id = Pubkey::default();
+
+        let size = unsafe {
+            solana_program::syscalls::sol_get_return_data(
+                data.as_mut_ptr(),
+                data.len() as u64,
+                &mut program_id,
+            )
+        };
+
+        if size == 0 {
+            None
+        } else {
+            Some((program_id, core::cmp::min(size as usize, data.len())))
+        }
+    }
+
+    #[cfg(all(feature = "mock", not(target_os = "solana")))]
+    {
+        mock::get_return_data(data)
+    }
+
+    #[cfg(all(not(feature = "mock"), not(target_os = "solana")))]
+    {
+        core::hint::black_box(data);
+        None
+    }
+}
+
+/// Set the return data of the running program.
+///
+/// Returns `InvalidArgument` if the data is longer than `MAX_RETURN_DATA`
+/// bytes, which the runtime would reject by aborting the program.
+#[inline(always)]
+pub fn set_return_data(data: &[u8]) -> ProgramResult {
+    if data.len() > MAX_RETURN_DATA {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    #[cfg(target_os = "solana")]
+    unsafe {
+        solana_program::syscalls::sol_set_return_data(data.as_ptr(), data.len() as u64)
+    };
+
+    #[cfg(all(feature = "mock", not(target_os = "solana")))]
+    mock::set_caller_return_data(data);
+
+    #[cfg(all(not(feature = "mock"), not(target_os = "solana")))]
+    core::hint::black_box(data);
 
     Ok(())
 }
@@ -101,3 +370,161 @@ pub fn write_bytes(destination: &mut [MaybeUninit<u8>], source: &[u8]) {
         d.write(*s);
     }
 }
+
+#[cfg(all(test, feature = "mock", not(target_os = "solana")))]
+mod tests {
+    use solana_program::{pubkey::MAX_SEED_LEN, system_instruction, system_program};
+
+    use super::*;
+    use crate::{mock::MockAccount, pda::find_program_address, signer::MAX_SIGNERS};
+
+    /// Transfer 100 lamports from `from`, signing with the meta of `from`.
+    fn transfer(
+        from: &MockAccount,
+        to: &MockAccount,
+        invoke: impl FnOnce(&InstructionC, &[&NoStdAccountInfo; 2]) -> ProgramResult,
+    ) -> ProgramResult {
+        let data = system_instruction::transfer(&from.key(), &to.key(), 100).data;
+        let metas = [
+            AccountMetaC {
+                pubkey: from.info().key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            to.info().to_meta_c(),
+        ];
+        let instruction = InstructionC {
+            program_id: &system_program::ID,
+            accounts: metas.as_ptr(),
+            accounts_len: 2,
+            data: data.as_ptr(),
+            data_len: data.len() as u64,
+        };
+
+        invoke(&instruction, &[from.info(), to.info()])
+    }
+
+    fn system_account(key: Pubkey) -> MockAccount {
+        MockAccount::new(key, system_program::ID, 1_000, &[]).writable(true)
+    }
+
+    #[test]
+    fn pda_signers_sign_their_address() {
+        mock::emulate_system_program(true);
+
+        let program_id = Pubkey::new_unique();
+        let (address, bump) = find_program_address(&[b"vault"], &program_id).unwrap();
+        let vault = system_account(address);
+        let to = system_account(Pubkey::new_unique());
+
+        transfer(&vault, &to, |instruction, accounts| {
+            invoke_signed(instruction, accounts, &program_id, &[&[b"vault", &[bump]]])
+        })
+        .unwrap();
+
+        let bump = [bump];
+        let seeds = [Seed::new(b"vault").unwrap(), Seed::from(&bump)];
+        let signers = [Signer::new(&seeds).unwrap()];
+        transfer(&vault, &to, |instruction, accounts| {
+            invoke_signed_with_signers(instruction, accounts, &program_id, &signers)
+        })
+        .unwrap();
+
+        assert_eq!(vault.lamports(), 800);
+        assert_eq!(to.lamports(), 1_200);
+    }
+
+    #[test]
+    fn other_seeds_do_not_sign() {
+        mock::emulate_system_program(true);
+
+        let program_id = Pubkey::new_unique();
+        let (address, bump) = find_program_address(&[b"vault"], &program_id).unwrap();
+        let vault = system_account(address);
+        let to = system_account(Pubkey::new_unique());
+
+        // seeds of another address
+        assert_eq!(
+            transfer(&vault, &to, |instruction, accounts| {
+                invoke_signed(instruction, accounts, &program_id, &[&[b"other"]])
+            }),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        // seeds of the address of another program
+        assert_eq!(
+            transfer(&vault, &to, |instruction, accounts| {
+                invoke_signed(
+                    instruction,
+                    accounts,
+                    &Pubkey::new_unique(),
+                    &[&[b"vault", &[bump]]],
+                )
+            }),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        // no seeds
+        assert_eq!(
+            transfer(&vault, &to, invoke),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        assert_eq!(vault.lamports(), 1_000);
+    }
+
+    #[test]
+    fn pda_signers_skip_invalid_and_extra_seeds() {
+        let program_id = Pubkey::new_unique();
+        let (address, bump) = find_program_address(&[b"vault"], &program_id).unwrap();
+        let bump = [bump];
+        let seeds: &[&[u8]] = &[b"vault", &bump];
+        let too_long: &[&[u8]] = &[&[0; MAX_SEED_LEN + 1]];
+
+        let signers_seeds = [too_long, seeds];
+        let signers = PdaSigners::new(&program_id, &signers_seeds);
+        assert_eq!(signers.len(), 2);
+        assert!(signers.signs(&address));
+        assert!(!signers.signs(&Pubkey::new_unique()));
+
+        let bump_seed = Seed::from(&bump);
+        let signer_seeds = [Seed::new(b"vault").unwrap(), bump_seed];
+        let signers = [Signer::new(&signer_seeds).unwrap()];
+        assert!(PdaSigners::from_signers(&program_id, &signers).signs(&address));
+
+        // the runtime rejects more than `MAX_SIGNERS` signers
+        let mut signers_seeds = [too_long; MAX_SIGNERS + 1];
+        signers_seeds[MAX_SIGNERS - 1] = seeds;
+        assert!(PdaSigners::new(&program_id, &signers_seeds).signs(&address));
+        signers_seeds.swap(MAX_SIGNERS - 1, MAX_SIGNERS);
+        assert!(!PdaSigners::new(&program_id, &signers_seeds).signs(&address));
+    }
+
+    #[test]
+    fn rejects_writable_escalation_and_other_keys() {
+        mock::emulate_system_program(true);
+
+        let from = system_account(Pubkey::new_unique()).signer(true);
+        let to = system_account(Pubkey::new_unique());
+        let read_only = MockAccount::new(to.key(), system_program::ID, 0, &[]);
+        let other = system_account(Pubkey::new_unique());
+
+        assert_eq!(
+            transfer(&from, &to, |instruction, _| invoke(
+                instruction,
+                &[from.info(), read_only.info()]
+            )),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        assert_eq!(
+            transfer(&from, &to, |instruction, _| invoke(
+                instruction,
+                &[from.info(), other.info()]
+            )),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        assert_eq!(from.lamports(), 1_000);
+    }
+}
@@ -0,0 +1,496 @@
+//! Host-side recorder for cross-program invocations.
+//!
+//! When the `mock` feature is enabled and the crate is not built for the
+//! `solana` target, [`invoke_unchecked`](crate::invoke_unchecked) records
+//! every instruction it receives in a thread-local log instead of discarding
+//! it. Tests can then inspect the log to assert which CPIs a handler issued.
+//!
+//! System program instructions can optionally be applied to the invoked
+//! accounts as well, see [`emulate_system_program`], and the return data of
+//! invoked programs can be simulated with [`set_next_return_data`]. Accounts
+//! for such tests can be created with [`MockAccount`], and the sysvars read
+//! with the `sol_get_*_sysvar` syscalls can be provided with [`set_sysvar`].
+
+mod account;
+mod system;
+
+pub use account::MockAccount;
+
+use core::{
+    cell::{Cell, RefCell},
+    mem::MaybeUninit,
+};
+use std::{thread_local, vec::Vec};
+
+use solana_nostd_entrypoint::{AccountInfoC, InstructionC};
+use solana_program::{
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::MAX_RETURN_DATA,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// An instruction passed to [`invoke_unchecked`](crate::invoke_unchecked),
+/// together with the seeds it was signed with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvokedInstruction {
+    /// The instruction as seen by the callee.
+    pub instruction: Instruction,
+
+    /// Seeds of the program derived addresses that signed the instruction.
+    pub signers_seeds: Vec<Vec<Vec<u8>>>,
+}
+
+thread_local! {
+    static INVOKED: RefCell<Vec<InvokedInstruction>> = const { RefCell::new(Vec::new()) };
+
+    static CALLER_PROGRAM_ID: Cell<Option<Pubkey>> = const { Cell::new(None) };
+
+    static EMULATE_SYSTEM_PROGRAM: Cell<bool> = const { Cell::new(false) };
+
+    static RETURN_DATA: RefCell<Option<(Pubkey, Vec<u8>)>> = const { RefCell::new(None) };
+
+    static NEXT_RETURN_DATA: RefCell<Option<(Pubkey, Vec<u8>)>> = const { RefCell::new(None) };
+
+    static SYSVARS: RefCell<Vec<(Pubkey, Vec<u8>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Set the program id of the program issuing CPIs on the current thread.
+///
+/// It is the program id of the return data set by the caller program through
+/// [`set_return_data`](crate::set_return_data).
+pub fn set_caller_program_id(program_id: Option<Pubkey>) {
+    CALLER_PROGRAM_ID.with(|caller| caller.set(program_id));
+}
+
+/// Enable or disable the system program emulation on the current thread.
+///
+/// When enabled, every invoked instruction is first checked for signer and
+/// writable privilege escalation, then `CreateAccount`, `Assign`, `Transfer`
+/// and `Allocate` instructions are applied to the invoked accounts.
+///
+/// The accounts must follow the runtime input layout, as [`MockAccount`]
+/// does: the data length is stored in the 8 bytes preceding the account data,
+/// and at least `MAX_PERMITTED_DATA_INCREASE` bytes are available after the
+/// data for `Allocate` to grow into.
+pub fn emulate_system_program(enabled: bool) {
+    EMULATE_SYSTEM_PROGRAM.with(|emulate| emulate.set(enabled));
+}
+
+/// Record an instruction in the current thread log and, if enabled, emulate
+/// its execution.
+///
+/// # Safety
+///
+/// The pointers in `instruction` must be valid for the lengths it declares and
+/// every element of `account_infos` must be initialized.
+pub(crate) unsafe fn invoke(
+    instruction: &InstructionC,
+    account_infos: &[MaybeUninit<AccountInfoC>],
+    program_id: &Pubkey,
+    signers_seeds: Vec<Vec<Vec<u8>>>,
+) -> ProgramResult {
+    let invoked = to_invoked_instruction(instruction, signers_seeds);
+    INVOKED.with(|log| log.borrow_mut().push(invoked.clone()));
+
+    // the runtime clears the return data before running the invoked program
+    let next_return_data = NEXT_RETURN_DATA.with(|next| next.borrow_mut().take());
+    RETURN_DATA.with(|return_data| *return_data.borrow_mut() = next_return_data);
+
+    if !EMULATE_SYSTEM_PROGRAM.with(Cell::get) {
+        return Ok(());
+    }
+
+    let infos = core::slice::from_raw_parts(
+        account_infos.as_ptr() as *const AccountInfoC,
+        account_infos.len(),
+    );
+
+    let signers = invoked
+        .signers_seeds
+        .iter()
+        .filter_map(|seeds| {
+            let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            Pubkey::create_program_address(&seeds, program_id).ok()
+        })
+        .collect::<Vec<_>>();
+
+    let accounts = invoked
+        .instruction
+        .accounts
+        .iter()
+        .map(|meta| {
+            let info = infos
+                .iter()
+                .find(|info| *info.key == meta.pubkey)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            if meta.is_signer && !info.is_signer && !signers.contains(&meta.pubkey) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            if meta.is_writable && !info.is_writable {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            Ok((meta, info))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if invoked.instruction.program_id == solana_program::system_program::ID {
+        system::process(&accounts, &invoked.instruction.data)
+    } else {
+        Ok(())
+    }
+}
+
+/// Set the data of a sysvar on the current thread, or remove it with `None`.
+///
+/// The data is what the `sol_get_*_sysvar` syscall of the sysvar writes: the
+/// in-memory layout of the native sysvar type. Host builds of the sysvar
+/// getters read it instead of calling the syscall.
+pub fn set_sysvar(id: &Pubkey, data: Option<&[u8]>) {
+    SYSVARS.with(|sysvars| {
+        let mut sysvars = sysvars.borrow_mut();
+        sysvars.retain(|(sysvar_id, _)| sysvar_id != id);
+
+        if let Some(data) = data {
+            sysvars.push((*id, data.to_vec()));
+        }
+    });
+}
+
+/// Data of a sysvar set on the current thread with [`set_sysvar`].
+pub fn sysvar(id: &Pubkey) -> Option<Vec<u8>> {
+    SYSVARS.with(|sysvars| {
+        sysvars
+            .borrow()
+            .iter()
+            .find(|(sysvar_id, _)| sysvar_id == id)
+            .map(|(_, data)| data.clone())
+    })
+}
+
+/// Set the return data of the next program invoked on the current thread, as
+/// if `program_id` had set it.
+///
+/// Every invocation clears the return data read by
+/// [`get_return_data`](crate::get_return_data), then sets the data staged by
+/// this function, if any. Empty data leaves the return data cleared.
+///
+/// # Panics
+///
+/// Panics if the data is longer than `MAX_RETURN_DATA` bytes.
+pub fn set_next_return_data(program_id: &Pubkey, data: &[u8]) {
+    assert!(
+        data.len() <= MAX_RETURN_DATA,
+        "return data is longer than MAX_RETURN_DATA"
+    );
+
+    NEXT_RETURN_DATA.with(|next| {
+        *next.borrow_mut() = (!data.is_empty()).then(|| (*program_id, data.to_vec()));
+    });
+}
+
+/// Return the return data set on the current thread, either by the caller
+/// program through [`set_return_data`](crate::set_return_data) or by a test.
+pub fn return_data() -> Option<(Pubkey, Vec<u8>)> {
+    RETURN_DATA.with(|return_data| return_data.borrow().clone())
+}
+
+/// Copy the return data of the current thread into `data`.
+pub(crate) fn get_return_data(data: &mut [u8]) -> Option<(Pubkey, usize)> {
+    RETURN_DATA.with(|return_data| {
+        return_data.borrow().as_ref().map(|(program_id, bytes)| {
+            let size = core::cmp::min(bytes.len(), data.len());
+            data[..size].copy_from_slice(&bytes[..size]);
+            (*program_id, size)
+        })
+    })
+}
+
+/// Set the return data of the current thread on behalf of the caller program.
+///
+/// Empty data clears the return data.
+pub(crate) fn set_caller_return_data(data: &[u8]) {
+    let program_id = CALLER_PROGRAM_ID.with(Cell::get).unwrap_or_default();
+
+    RETURN_DATA.with(|return_data| {
+        *return_data.borrow_mut() = (!data.is_empty()).then(|| (program_id, data.to_vec()));
+    });
+}
+
+/// Return a copy of the instructions invoked on the current thread, in
+/// invocation order.
+pub fn invoked_instructions() -> Vec<InvokedInstruction> {
+    INVOKED.with(|log| log.borrow().clone())
+}
+
+/// Return the instructions invoked on the current thread and clear the log.
+pub fn take_invoked_instructions() -> Vec<InvokedInstruction> {
+    INVOKED.with(|log| core::mem::take(&mut *log.borrow_mut()))
+}
+
+/// Clear the instructions invoked on the current thread.
+pub fn clear_invoked_instructions() {
+    INVOKED.with(|log| log.borrow_mut().clear());
+}
+
+/// Copy a C-ABI instruction into an owned [`InvokedInstruction`].
+///
+/// # Safety
+///
+/// The pointers in `instruction` must be valid for the lengths it declares.
+unsafe fn to_invoked_instruction(
+    instruction: &InstructionC,
+    signers_seeds: Vec<Vec<Vec<u8>>>,
+) -> InvokedInstruction {
+    let metas =
+        core::slice::from_raw_parts(instruction.accounts, instruction.accounts_len as usize);
+    let data = core::slice::from_raw_parts(instruction.data, instruction.data_len as usize);
+
+    InvokedInstruction {
+        instruction: Instruction {
+            program_id: *instruction.program_id,
+            accounts: metas
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: *meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: data.to_vec(),
+        },
+        signers_seeds,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        entrypoint::MAX_PERMITTED_DATA_INCREASE, system_instruction, system_program,
+    };
+
+    use super::*;
+    use crate::PdaSigners;
+
+    /// Invoke `instruction` with the accounts, in order, as its metas.
+    fn invoke_with<const N: usize>(
+        instruction: &Instruction,
+        accounts: [&MockAccount; N],
+        signers: PdaSigners,
+    ) -> ProgramResult {
+        let metas = accounts.map(|account| account.info().to_meta_c());
+        let instruction = InstructionC {
+            program_id: &instruction.program_id,
+            accounts: metas.as_ptr(),
+            accounts_len: N as u64,
+            data: instruction.data.as_ptr(),
+            data_len: instruction.data.len() as u64,
+        };
+
+        crate::invoke_signed_with(&instruction, &accounts.map(MockAccount::info), signers)
+    }
+
+    fn system_account(lamports: u64) -> MockAccount {
+        MockAccount::new(Pubkey::new_unique(), system_program::ID, lamports, &[])
+            .signer(true)
+            .writable(true)
+    }
+
+    #[test]
+    fn records_invoked_instructions() {
+        emulate_system_program(false);
+        clear_invoked_instructions();
+
+        let from = system_account(10);
+        let to = system_account(0).signer(false);
+        let transfer = system_instruction::transfer(&from.key(), &to.key(), 5);
+
+        let program_id = Pubkey::new_unique();
+        invoke_with(
+            &transfer,
+            [&from, &to],
+            PdaSigners::new(&program_id, &[&[b"seed", &[255]]]),
+        )
+        .unwrap();
+
+        let expected = InvokedInstruction {
+            instruction: transfer,
+            signers_seeds: std::vec![std::vec![b"seed".to_vec(), std::vec![255]]],
+        };
+        let invoked = invoked_instructions();
+        assert_eq!(invoked, [expected]);
+        assert_eq!(take_invoked_instructions(), invoked);
+        assert!(invoked_instructions().is_empty());
+
+        // the instruction is only recorded
+        assert_eq!(from.lamports(), 10);
+    }
+
+    #[test]
+    fn emulates_create_account() {
+        emulate_system_program(true);
+
+        let from = system_account(1_000);
+        let to = system_account(0);
+        let owner = Pubkey::new_unique();
+
+        let create_account =
+            system_instruction::create_account(&from.key(), &to.key(), 600, 16, &owner);
+        invoke_with(&create_account, [&from, &to], PdaSigners::NONE).unwrap();
+
+        assert_eq!(from.lamports(), 400);
+        assert_eq!(to.lamports(), 600);
+        assert_eq!(to.owner(), owner);
+        assert_eq!(to.data(), [0; 16]);
+    }
+
+    #[test]
+    fn emulates_transfer_assign_and_allocate() {
+        emulate_system_program(true);
+
+        let from = system_account(1_000);
+        let to = system_account(0);
+        let owner = Pubkey::new_unique();
+
+        invoke_with(
+            &system_instruction::transfer(&from.key(), &to.key(), 300),
+            [&from, &to],
+            PdaSigners::NONE,
+        )
+        .unwrap();
+        invoke_with(
+            &system_instruction::allocate(&to.key(), 8),
+            [&to],
+            PdaSigners::NONE,
+        )
+        .unwrap();
+        invoke_with(
+            &system_instruction::assign(&to.key(), &owner),
+            [&to],
+            PdaSigners::NONE,
+        )
+        .unwrap();
+
+        assert_eq!(from.lamports(), 700);
+        assert_eq!(to.lamports(), 300);
+        assert_eq!(to.data(), [0; 8]);
+        assert_eq!(to.owner(), owner);
+
+        // the account is no longer owned by the system program
+        assert_eq!(
+            invoke_with(
+                &system_instruction::allocate(&to.key(), 8),
+                [&to],
+                PdaSigners::NONE
+            ),
+            Err(ProgramError::Custom(
+                system_instruction::SystemError::AccountAlreadyInUse as u32
+            ))
+        );
+    }
+
+    #[test]
+    fn failed_create_account_leaves_the_accounts_unchanged() {
+        emulate_system_program(true);
+
+        let from = system_account(1_000);
+        let to = system_account(0);
+        let owner = Pubkey::new_unique();
+
+        // not enough lamports
+        let create_account =
+            system_instruction::create_account(&from.key(), &to.key(), 2_000, 16, &owner);
+        assert_eq!(
+            invoke_with(&create_account, [&from, &to], PdaSigners::NONE),
+            Err(ProgramError::Custom(
+                system_instruction::SystemError::ResultWithNegativeLamports as u32
+            ))
+        );
+
+        // too much space, after the transfer succeeded
+        let create_account = system_instruction::create_account(
+            &from.key(),
+            &to.key(),
+            600,
+            MAX_PERMITTED_DATA_INCREASE as u64 + 1,
+            &owner,
+        );
+        assert_eq!(
+            invoke_with(&create_account, [&from, &to], PdaSigners::NONE),
+            Err(ProgramError::InvalidRealloc)
+        );
+
+        assert_eq!(from.lamports(), 1_000);
+        assert_eq!(to.lamports(), 0);
+        assert_eq!(to.owner(), system_program::ID);
+        assert!(to.data().is_empty());
+    }
+
+    #[test]
+    fn invocations_replace_the_return_data() {
+        emulate_system_program(false);
+
+        let caller = Pubkey::new_unique();
+        set_caller_program_id(Some(caller));
+        crate::set_return_data(b"caller").unwrap();
+        assert_eq!(return_data(), Some((caller, b"caller".to_vec())));
+
+        let from = system_account(10);
+        let to = system_account(0).signer(false);
+        let transfer = system_instruction::transfer(&from.key(), &to.key(), 5);
+
+        let callee = Pubkey::new_unique();
+        set_next_return_data(&callee, b"callee");
+        invoke_with(&transfer, [&from, &to], PdaSigners::NONE).unwrap();
+
+        let mut data = [0; 4];
+        assert_eq!(crate::get_return_data(&mut data), Some((callee, 4)));
+        assert_eq!(&data, b"call");
+
+        // the next invoked program does not set return data
+        invoke_with(&transfer, [&from, &to], PdaSigners::NONE).unwrap();
+        assert_eq!(crate::get_return_data(&mut data), None);
+    }
+
+    #[test]
+    fn return_data_is_bounded() {
+        assert_eq!(
+            crate::set_return_data(&[1; MAX_RETURN_DATA + 1]),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(crate::set_return_data(&[1; MAX_RETURN_DATA]), Ok(()));
+        assert_eq!(return_data().unwrap().1.len(), MAX_RETURN_DATA);
+    }
+
+    #[test]
+    fn rejects_missing_signatures() {
+        emulate_system_program(true);
+
+        let from = system_account(1_000).signer(false);
+        let to = system_account(0);
+        let transfer = system_instruction::transfer(&from.key(), &to.key(), 300);
+
+        assert_eq!(
+            invoke_with(&transfer, [&from, &to], PdaSigners::NONE),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+        assert_eq!(from.lamports(), 1_000);
+    }
+
+    #[test]
+    fn sysvars_are_replaced_and_removed() {
+        let id = Pubkey::new_unique();
+        assert_eq!(sysvar(&id), None);
+
+        set_sysvar(&id, Some(&[1, 2]));
+        set_sysvar(&id, Some(&[3]));
+        assert_eq!(sysvar(&id), Some(std::vec![3]));
+
+        set_sysvar(&id, None);
+        assert_eq!(sysvar(&id), None);
+    }
+}
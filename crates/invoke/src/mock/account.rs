@@ -0,0 +1,105 @@
+//! Accounts laid out like the runtime input, for tests of CPI handlers.
+
+use std::boxed::Box;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::MAX_PERMITTED_DATA_INCREASE, pubkey::Pubkey};
+
+/// Length of the account header preceding the account data: borrow state,
+/// signer, writable and executable flags, 4 bytes of padding, key, owner,
+/// lamports and data length.
+const HEADER_LEN: usize = 8 + 32 + 32 + 8 + 8;
+
+const KEY_OFFSET: usize = 8;
+
+const OWNER_OFFSET: usize = KEY_OFFSET + 32;
+
+const LAMPORTS_OFFSET: usize = OWNER_OFFSET + 32;
+
+const DATA_LEN_OFFSET: usize = LAMPORTS_OFFSET + 8;
+
+/// An account owning its memory in the runtime input layout.
+///
+/// The data is followed by `MAX_PERMITTED_DATA_INCREASE` bytes to grow into
+/// and the rent epoch, so the account can be passed to handlers and to the
+/// system program emulation like an account of the program input.
+pub struct MockAccount {
+    /// Account info pointing to the account header.
+    info: NoStdAccountInfo,
+
+    /// Number of 8-byte words allocated for the account.
+    words: usize,
+}
+
+impl MockAccount {
+    /// Create a non-signer, read-only account.
+    pub fn new(key: Pubkey, owner: Pubkey, lamports: u64, data: &[u8]) -> Self {
+        let len = HEADER_LEN + data.len() + MAX_PERMITTED_DATA_INCREASE + 8;
+        let words = len.div_ceil(8);
+        let buffer = Box::into_raw(std::vec![0u64; words].into_boxed_slice());
+
+        // `NoStdAccountInfo` is a pointer to the account header
+        let account = Self {
+            info: unsafe { core::mem::transmute::<*mut u8, NoStdAccountInfo>(buffer as *mut u8) },
+            words,
+        };
+
+        let bytes = account.bytes_mut();
+        bytes[KEY_OFFSET..OWNER_OFFSET].copy_from_slice(key.as_ref());
+        bytes[OWNER_OFFSET..LAMPORTS_OFFSET].copy_from_slice(owner.as_ref());
+        bytes[LAMPORTS_OFFSET..DATA_LEN_OFFSET].copy_from_slice(&lamports.to_le_bytes());
+        bytes[DATA_LEN_OFFSET..HEADER_LEN].copy_from_slice(&(data.len() as u64).to_le_bytes());
+        bytes[HEADER_LEN..HEADER_LEN + data.len()].copy_from_slice(data);
+
+        account
+    }
+
+    /// Set whether the account signed the transaction.
+    pub fn signer(self, is_signer: bool) -> Self {
+        self.bytes_mut()[1] = is_signer as u8;
+        self
+    }
+
+    /// Set whether the account is writable.
+    pub fn writable(self, is_writable: bool) -> Self {
+        self.bytes_mut()[2] = is_writable as u8;
+        self
+    }
+
+    /// Account info over the account memory.
+    pub fn info(&self) -> &NoStdAccountInfo {
+        &self.info
+    }
+
+    pub fn key(&self) -> Pubkey {
+        *self.info().key()
+    }
+
+    pub fn owner(&self) -> Pubkey {
+        *self.info().owner()
+    }
+
+    pub fn lamports(&self) -> u64 {
+        unsafe { *self.info().unchecked_borrow_lamports() }
+    }
+
+    pub fn data(&self) -> &[u8] {
+        unsafe { self.info().unchecked_borrow_data() }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    fn bytes_mut(&self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.header(), self.words * 8) }
+    }
+
+    fn header(&self) -> *mut u8 {
+        unsafe { core::mem::transmute_copy::<NoStdAccountInfo, *mut u8>(&self.info) }
+    }
+}
+
+impl Drop for MockAccount {
+    fn drop(&mut self) {
+        let buffer = core::ptr::slice_from_raw_parts_mut(self.header() as *mut u64, self.words);
+        drop(unsafe { Box::from_raw(buffer) });
+    }
+}
@@ -0,0 +1,197 @@
+//! Emulation of the system program instructions used by CPI tests.
+
+use std::vec::Vec;
+
+use solana_nostd_entrypoint::AccountInfoC;
+use solana_program::{
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    instruction::AccountMeta,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction::{SystemError, MAX_PERMITTED_DATA_LENGTH},
+    system_program,
+};
+
+/// Apply a system program instruction to the invoked accounts.
+///
+/// Instructions other than `CreateAccount`, `Assign`, `Transfer` and
+/// `Allocate` are accepted without being applied. Like the runtime, a failed
+/// instruction leaves the lamports, owner and data length of the accounts
+/// unchanged.
+pub(super) fn process(accounts: &[(&AccountMeta, &AccountInfoC)], data: &[u8]) -> ProgramResult {
+    let snapshot = accounts
+        .iter()
+        .map(|(_, info)| unsafe { (*info.lamports, *info.owner, *data_len_ptr(info)) })
+        .collect::<Vec<_>>();
+
+    let result = apply(accounts, data);
+
+    if result.is_err() {
+        for ((_, info), (lamports, owner, len)) in accounts.iter().zip(snapshot) {
+            unsafe {
+                *(info.lamports as *mut u64) = lamports;
+                *(info.owner as *mut Pubkey) = owner;
+                *data_len_ptr(info) = len;
+            }
+        }
+    }
+
+    result
+}
+
+fn apply(accounts: &[(&AccountMeta, &AccountInfoC)], data: &[u8]) -> ProgramResult {
+    let discriminator = read_u32(data, 0)?;
+
+    match discriminator {
+        // - [4..12 ]: lamports
+        // - [12..20]: account space
+        // - [20..52]: owner pubkey
+        0 => {
+            let [from, to, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            if unsafe { *to.1.lamports } > 0 {
+                return Err(ProgramError::Custom(
+                    SystemError::AccountAlreadyInUse as u32,
+                ));
+            }
+
+            transfer(from, to, read_u64(data, 4)?)?;
+            allocate(to, read_u64(data, 12)?)?;
+            assign(to, &read_pubkey(data, 20)?)
+        }
+        // - [4..36]: owner pubkey
+        1 => {
+            let [account, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            assign(account, &read_pubkey(data, 4)?)
+        }
+        // - [4..12]: lamports amount
+        2 => {
+            let [from, to, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            transfer(from, to, read_u64(data, 4)?)
+        }
+        // - [4..12]: space
+        8 => {
+            let [account, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            allocate(account, read_u64(data, 4)?)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn allocate((meta, info): &(&AccountMeta, &AccountInfoC), space: u64) -> ProgramResult {
+    if !meta.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let data_len = unsafe { &mut *data_len_ptr(info) };
+
+    if *data_len != 0 || unsafe { *info.owner } != system_program::ID {
+        return Err(ProgramError::Custom(
+            SystemError::AccountAlreadyInUse as u32,
+        ));
+    }
+
+    if space > MAX_PERMITTED_DATA_LENGTH {
+        return Err(ProgramError::Custom(
+            SystemError::InvalidAccountDataLength as u32,
+        ));
+    }
+
+    if space as usize > MAX_PERMITTED_DATA_INCREASE {
+        return Err(ProgramError::InvalidRealloc);
+    }
+
+    unsafe { core::ptr::write_bytes(info.data as *mut u8, 0, space as usize) };
+    *data_len = space;
+
+    Ok(())
+}
+
+fn assign((meta, info): &(&AccountMeta, &AccountInfoC), owner: &Pubkey) -> ProgramResult {
+    let current_owner = unsafe { &mut *(info.owner as *mut Pubkey) };
+
+    if current_owner == owner {
+        return Ok(());
+    }
+
+    if !meta.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *current_owner != system_program::ID {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    *current_owner = *owner;
+
+    Ok(())
+}
+
+fn transfer(
+    (from_meta, from): &(&AccountMeta, &AccountInfoC),
+    (_, to): &(&AccountMeta, &AccountInfoC),
+    lamports: u64,
+) -> ProgramResult {
+    if !from_meta.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if unsafe { *data_len_ptr(from) } != 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if unsafe { *from.owner } != system_program::ID {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let from_lamports = unsafe { &mut *(from.lamports as *mut u64) };
+
+    if *from_lamports < lamports {
+        return Err(ProgramError::Custom(
+            SystemError::ResultWithNegativeLamports as u32,
+        ));
+    }
+
+    *from_lamports -= lamports;
+
+    let to_lamports = unsafe { &mut *(to.lamports as *mut u64) };
+    *to_lamports = to_lamports
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Data length of an account, stored right before the account data.
+fn data_len_ptr(info: &AccountInfoC) -> *mut u64 {
+    unsafe { (info.data as *mut u64).sub(1) }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, ProgramError> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+    data.get(offset..offset + 8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey, ProgramError> {
+    data.get(offset..offset + 32)
+        .map(|bytes| Pubkey::try_from(bytes).unwrap())
+        .ok_or(ProgramError::InvalidInstructionData)
+}
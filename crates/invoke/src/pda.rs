@@ -0,0 +1,239 @@
+//! Program derived address helpers.
+//!
+//! On-chain the addresses are derived by the `sol_create_program_address`
+//! and `sol_try_find_program_address` syscalls. Elsewhere they are derived
+//! with SHA-256 and a curve check, so host tests get the same addresses.
+
+use solana_program::{
+    hash::hashv,
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+};
+
+/// Marker appended to the seeds of a program derived address.
+pub const PDA_MARKER: &[u8; 21] = b"ProgramDerivedAddress";
+
+/// Create a program derived address from the given seeds, which include the
+/// bump seed, if any.
+///
+/// Returns `InvalidSeeds` if the resulting address lies on the ed25519
+/// curve.
+#[inline(always)]
+pub fn create_program_address(
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    check_seeds(seeds, 0)?;
+
+    #[cfg(target_os = "solana")]
+    {
+        let mut address = Pubkey::default();
+
+        let result = unsafe {
+            solana_program::syscalls::sol_create_program_address(
+                seeds as *const _ as *const u8,
+                seeds.len() as u64,
+                program_id as *const _ as *const u8,
+                &mut address as *mut _ as *mut u8,
+            )
+        };
+
+        if result == solana_program::entrypoint::SUCCESS {
+            Ok(address)
+        } else {
+            Err(ProgramError::InvalidSeeds)
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let address = derive_address(seeds, None, program_id);
+
+        if address.is_on_curve() {
+            Err(ProgramError::InvalidSeeds)
+        } else {
+            Ok(address)
+        }
+    }
+}
+
+/// Find a valid program derived address and its bump seed.
+///
+/// Bump seeds are tried from 255 down to 1, like the
+/// `sol_try_find_program_address` syscall, and the first address that does
+/// not lie on the ed25519 curve is returned. Returns `InvalidSeeds` if there
+/// is none.
+#[inline(always)]
+pub fn find_program_address(
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<(Pubkey, u8), ProgramError> {
+    check_seeds(seeds, 1)?;
+
+    #[cfg(target_os = "solana")]
+    {
+        let mut address = Pubkey::default();
+        let mut bump = u8::MAX;
+
+        let result = unsafe {
+            solana_program::syscalls::sol_try_find_program_address(
+                seeds as *const _ as *const u8,
+                seeds.len() as u64,
+                program_id as *const _ as *const u8,
+                &mut address as *mut _ as *mut u8,
+                &mut bump as *mut u8,
+            )
+        };
+
+        if result == solana_program::entrypoint::SUCCESS {
+            Ok((address, bump))
+        } else {
+            Err(ProgramError::InvalidSeeds)
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        (1..=u8::MAX)
+            .rev()
+            .map(|bump| (derive_address(seeds, Some(bump), program_id), bump))
+            .find(|(address, _)| !address.is_on_curve())
+            .ok_or(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Verify that `expected` is the program derived address of the given seeds
+/// and bump seed.
+///
+/// This only hashes the seeds and skips the curve check, so it is cheaper
+/// than [`create_program_address`]. `expected` is assumed to be a valid
+/// program derived address, e.g. the key of an account created with the same
+/// seeds. Returns `InvalidSeeds` if the addresses differ.
+#[inline(always)]
+pub fn verify_pda(
+    seeds: &[&[u8]],
+    bump: u8,
+    program_id: &Pubkey,
+    expected: &Pubkey,
+) -> Result<(), ProgramError> {
+    check_seeds(seeds, 1)?;
+
+    if derive_address(seeds, Some(bump), program_id) == *expected {
+        Ok(())
+    } else {
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Check the number and length of the seeds, reserving `reserved` seeds for
+/// the bump seed.
+#[inline(always)]
+fn check_seeds(seeds: &[&[u8]], reserved: usize) -> Result<(), ProgramError> {
+    if seeds.len() + reserved > MAX_SEEDS {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    if seeds.iter().any(|seed| seed.len() > MAX_SEED_LEN) {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    Ok(())
+}
+
+/// Hash the seeds, bump seed, program id and marker of a program derived
+/// address, without checking whether the result lies on the curve.
+///
+/// The seeds must have been checked with [`check_seeds`].
+#[inline(always)]
+fn derive_address(seeds: &[&[u8]], bump: Option<u8>, program_id: &Pubkey) -> Pubkey {
+    let bump = bump.map(|bump| [bump]);
+
+    let mut values: [&[u8]; MAX_SEEDS + 2] = [&[]; MAX_SEEDS + 2];
+    let mut len = 0;
+
+    for seed in seeds
+        .iter()
+        .copied()
+        .chain(bump.as_ref().map(|bump| &bump[..]))
+    {
+        values[len] = seed;
+        len += 1;
+    }
+
+    values[len] = program_id.as_ref();
+    values[len + 1] = PDA_MARKER;
+
+    Pubkey::new_from_array(hashv(&values[..len + 2]).to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_program_address_matches_pubkey() {
+        let program_id = Pubkey::new_unique();
+
+        for index in 0..64u32 {
+            let seed = index.to_le_bytes();
+            let seeds: &[&[u8]] = &[b"vault", &seed];
+
+            assert_eq!(
+                find_program_address(seeds, &program_id),
+                Ok(Pubkey::find_program_address(seeds, &program_id))
+            );
+        }
+    }
+
+    #[test]
+    fn create_program_address_matches_pubkey() {
+        let program_id = Pubkey::new_unique();
+
+        for bump in [u8::MAX, 254, 1, 0] {
+            let seeds: &[&[u8]] = &[b"vault", &[bump]];
+
+            assert_eq!(
+                create_program_address(seeds, &program_id),
+                Pubkey::create_program_address(seeds, &program_id)
+                    .map_err(|_| ProgramError::InvalidSeeds)
+            );
+        }
+    }
+
+    #[test]
+    fn verify_pda_checks_the_address() {
+        let program_id = Pubkey::new_unique();
+        let (address, bump) = Pubkey::find_program_address(&[b"vault"], &program_id);
+
+        assert_eq!(verify_pda(&[b"vault"], bump, &program_id, &address), Ok(()));
+        assert_eq!(
+            verify_pda(&[b"vault"], bump.wrapping_sub(1), &program_id, &address),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            verify_pda(&[b"other"], bump, &program_id, &address),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn seeds_are_bounded() {
+        let program_id = Pubkey::new_unique();
+        let long_seed = [0; MAX_SEED_LEN + 1];
+        let many_seeds: [&[u8]; MAX_SEEDS] = [b"seed"; MAX_SEEDS];
+
+        assert_eq!(
+            find_program_address(&[&long_seed], &program_id),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+        assert_eq!(
+            find_program_address(&many_seeds, &program_id),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+        // the bump seed is part of the seeds of create_program_address
+        assert_ne!(
+            create_program_address(&many_seeds, &program_id),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+    }
+}
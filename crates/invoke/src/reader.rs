@@ -0,0 +1,121 @@
+//! Reading of Borsh and bincode serialized values from borrowed bytes.
+
+use solana_program::pubkey::Pubkey;
+
+/// Reads serialized values from borrowed bytes.
+///
+/// Borsh and bincode share the little-endian layout of integers and pubkeys
+/// but prefix strings with a `u32` and a `u64` length respectively. Reads
+/// return `None` past the end of the data, so callers choose the error, e.g.
+/// `InvalidAccountData` or `InvalidInstructionData`.
+#[derive(Clone, Copy, Debug)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    #[inline(always)]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Bytes not read yet.
+    #[inline(always)]
+    pub fn remaining(&self) -> &'a [u8] {
+        self.data
+    }
+
+    #[inline(always)]
+    pub fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.data.len() < len {
+            return None;
+        }
+
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+
+        Some(bytes)
+    }
+
+    #[inline(always)]
+    pub fn read_u32(&mut self) -> Option<u32> {
+        let bytes = self.read_bytes(4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    #[inline(always)]
+    pub fn read_u64(&mut self) -> Option<u64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.read_bytes(8)?);
+        Some(u64::from_le_bytes(bytes))
+    }
+
+    #[inline(always)]
+    pub fn read_pubkey(&mut self) -> Option<&'a Pubkey> {
+        let bytes = self.read_bytes(32)?;
+        Some(unsafe { &*(bytes.as_ptr() as *const Pubkey) })
+    }
+
+    /// Read a Borsh string, with its `u32` length prefix.
+    #[inline(always)]
+    pub fn read_borsh_str(&mut self) -> Option<&'a str> {
+        let len = self.read_u32()? as usize;
+        self.read_utf8(len)
+    }
+
+    /// Read a bincode string, with its `u64` length prefix.
+    #[inline(always)]
+    pub fn read_bincode_str(&mut self) -> Option<&'a str> {
+        let len = usize::try_from(self.read_u64()?).ok()?;
+        self.read_utf8(len)
+    }
+
+    #[inline(always)]
+    fn read_utf8(&mut self, len: usize) -> Option<&'a str> {
+        core::str::from_utf8(self.read_bytes(len)?).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_values_in_order() {
+        let key = Pubkey::new_from_array([7; 32]);
+        let mut data = [0; 4 + 8 + 32 + 1];
+        data[..4].copy_from_slice(&1u32.to_le_bytes());
+        data[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+        data[12..44].copy_from_slice(key.as_ref());
+        data[44] = 9;
+
+        let mut reader = Reader::new(&data);
+        assert_eq!(reader.read_u32(), Some(1));
+        assert_eq!(reader.read_u64(), Some(u64::MAX));
+        assert_eq!(reader.read_pubkey(), Some(&key));
+        assert_eq!(reader.remaining(), &[9]);
+
+        // a failed read consumes nothing
+        assert_eq!(reader.read_u32(), None);
+        assert_eq!(reader.read_bytes(1), Some(&[9][..]));
+        assert_eq!(reader.read_bytes(0), Some(&[][..]));
+        assert_eq!(reader.read_bytes(1), None);
+    }
+
+    #[test]
+    fn reads_strings_with_their_length_prefix() {
+        let borsh = [3, 0, 0, 0, b'a', b'b', b'c'];
+        assert_eq!(Reader::new(&borsh).read_borsh_str(), Some("abc"));
+        assert_eq!(Reader::new(&borsh[..6]).read_borsh_str(), None);
+
+        let bincode = [2, 0, 0, 0, 0, 0, 0, 0, b'h', b'i'];
+        assert_eq!(Reader::new(&bincode).read_bincode_str(), Some("hi"));
+        assert_eq!(Reader::new(&bincode[..9]).read_bincode_str(), None);
+
+        let invalid_utf8 = [1, 0, 0, 0, 0xff];
+        assert_eq!(Reader::new(&invalid_utf8).read_borsh_str(), None);
+
+        let huge = [0xff; 8];
+        assert_eq!(Reader::new(&huge).read_bincode_str(), None);
+    }
+}
@@ -0,0 +1,289 @@
+//! C-ABI signer seeds for program derived address signing.
+//!
+//! `sol_invoke_signed_c` expects signer seeds as arrays of `SolSignerSeedsC`
+//! and `SolSignerSeedC`, i.e. address and length pairs. [`Signer`] and
+//! [`Seed`] have exactly that layout, so they can be passed to the syscall
+//! without relying on the layout of Rust slices. Rust seeds are passed as
+//! they are too, after checking at compile time that slices have that
+//! layout.
+//!
+//! [`PdaSigners`] pairs the seeds with the id of the program deriving the
+//! addresses, so the invoked accounts can be checked against them.
+
+use core::marker::PhantomData;
+
+use solana_program::{
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+};
+
+use crate::pda::create_program_address;
+
+/// A single seed of a program derived address (`SolSignerSeedC`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Seed<'a> {
+    /// Address of the seed bytes.
+    addr: *const u8,
+
+    /// Length of the seed.
+    len: u64,
+
+    _bytes: PhantomData<&'a [u8]>,
+}
+
+impl<'a> Seed<'a> {
+    /// Create a seed, checking that it is no longer than `MAX_SEED_LEN`.
+    #[inline(always)]
+    pub fn new(seed: &'a [u8]) -> Result<Self, ProgramError> {
+        if seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
+        Ok(Self {
+            addr: seed.as_ptr(),
+            len: seed.len() as u64,
+            _bytes: PhantomData,
+        })
+    }
+
+    /// Return the seed bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        unsafe { core::slice::from_raw_parts(self.addr, self.len as usize) }
+    }
+}
+
+impl<'a> From<&'a [u8; 1]> for Seed<'a> {
+    /// Create a seed from a bump, which is always a valid seed.
+    #[inline(always)]
+    fn from(bump: &'a [u8; 1]) -> Self {
+        Self {
+            addr: bump.as_ptr(),
+            len: 1,
+            _bytes: PhantomData,
+        }
+    }
+}
+
+/// The seeds of a program derived address signing an instruction
+/// (`SolSignerSeedsC`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Signer<'a, 'b> {
+    /// Address of the seeds.
+    addr: *const Seed<'a>,
+
+    /// Number of seeds.
+    len: u64,
+
+    _seeds: PhantomData<&'b [Seed<'a>]>,
+}
+
+impl<'a, 'b> Signer<'a, 'b> {
+    /// Create a signer, checking that it has no more than `MAX_SEEDS` seeds.
+    #[inline(always)]
+    pub fn new(seeds: &'b [Seed<'a>]) -> Result<Self, ProgramError> {
+        if seeds.len() > MAX_SEEDS {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(Self {
+            addr: seeds.as_ptr(),
+            len: seeds.len() as u64,
+            _seeds: PhantomData,
+        })
+    }
+
+    /// Return the seeds of the signer.
+    #[inline(always)]
+    pub fn seeds(&self) -> &'b [Seed<'a>] {
+        unsafe { core::slice::from_raw_parts(self.addr, self.len as usize) }
+    }
+}
+
+/// Address and length of a Rust slice, the layout of [`Seed`] and [`Signer`].
+#[repr(C)]
+struct RawSlice {
+    addr: *const u8,
+    len: u64,
+}
+
+// Rust does not guarantee the layout of slice references, so check at
+// compile time that `&[u8]` and `&[&[u8]]` are laid out like `Seed` and
+// `Signer`, with the address first. `PdaSigners` relies on it to pass Rust
+// seeds to `sol_invoke_signed_c` without copying them.
+const _: () = {
+    use core::mem::{align_of, size_of, transmute};
+
+    assert!(size_of::<&[u8]>() == size_of::<Seed>());
+    assert!(align_of::<&[u8]>() == align_of::<Seed>());
+    assert!(size_of::<&[&[u8]]>() == size_of::<Signer>());
+    assert!(align_of::<&[&[u8]]>() == align_of::<Signer>());
+
+    let seed: &[u8] = &[0; 3];
+    let raw = unsafe { transmute::<&[u8], RawSlice>(seed) };
+    assert!(raw.len == 3);
+
+    let seeds: &[&[u8]] = &[&[], &[]];
+    let raw = unsafe { transmute::<&[&[u8]], RawSlice>(seeds) };
+    assert!(raw.len == 2);
+};
+
+/// Maximum number of program derived addresses signing a cross-program
+/// invocation, as enforced by the runtime.
+pub const MAX_SIGNERS: usize = 16;
+
+/// The program derived addresses signing an instruction: the seeds of each
+/// address and the id of the program they are derived from.
+///
+/// The seeds are either Rust slices or C-ABI [`Signer`]s, and are passed to
+/// the runtime as they are. The addresses are derived once, when the signers
+/// are created, so checking several accounts against them costs no further
+/// derivation.
+#[derive(Clone, Copy)]
+pub struct PdaSigners<'a> {
+    /// Program deriving the addresses.
+    program_id: &'a Pubkey,
+
+    /// Seeds of the addresses.
+    seeds: SignersSeeds<'a>,
+
+    /// Addresses derived from the seeds, without the invalid ones.
+    addresses: [Pubkey; MAX_SIGNERS],
+
+    /// Number of `addresses`.
+    addresses_len: usize,
+}
+
+#[derive(Clone, Copy)]
+enum SignersSeeds<'a> {
+    Slices(&'a [&'a [&'a [u8]]]),
+    Signers(&'a [Signer<'a, 'a>]),
+}
+
+impl<'a> PdaSigners<'a> {
+    /// No program derived address signs the instruction.
+    pub const NONE: PdaSigners<'static> = PdaSigners {
+        program_id: &Pubkey::new_from_array([0; 32]),
+        seeds: SignersSeeds::Slices(&[]),
+        addresses: [Pubkey::new_from_array([0; 32]); MAX_SIGNERS],
+        addresses_len: 0,
+    };
+
+    /// Addresses of `program_id` derived from each of `signers_seeds`.
+    #[inline(always)]
+    pub fn new(program_id: &'a Pubkey, signers_seeds: &'a [&'a [&'a [u8]]]) -> Self {
+        Self::derive(program_id, SignersSeeds::Slices(signers_seeds))
+    }
+
+    /// Addresses of `program_id` derived from the seeds of each of
+    /// `signers`.
+    #[inline(always)]
+    pub fn from_signers(program_id: &'a Pubkey, signers: &'a [Signer<'a, 'a>]) -> Self {
+        Self::derive(program_id, SignersSeeds::Signers(signers))
+    }
+
+    /// Derive the address of each of the seeds.
+    ///
+    /// Seeds that do not derive a valid address sign nothing, and neither do
+    /// seeds past the first `MAX_SIGNERS`, which the runtime rejects.
+    fn derive(program_id: &'a Pubkey, seeds: SignersSeeds<'a>) -> Self {
+        let mut signers = Self {
+            program_id,
+            seeds,
+            ..Self::NONE
+        };
+
+        let mut push = |seeds: &[&[u8]]| {
+            if let Ok(address) = create_program_address(seeds, program_id) {
+                signers.addresses[signers.addresses_len] = address;
+                signers.addresses_len += 1;
+            }
+        };
+
+        match seeds {
+            SignersSeeds::Slices(signers_seeds) => signers_seeds
+                .iter()
+                .take(MAX_SIGNERS)
+                .for_each(|seeds| push(seeds)),
+            SignersSeeds::Signers(signers) => {
+                signers.iter().take(MAX_SIGNERS).for_each(|signer| {
+                    let mut seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+
+                    for (seed, bytes) in signer.seeds().iter().zip(seeds.iter_mut()) {
+                        *bytes = seed.as_bytes();
+                    }
+
+                    // more than `MAX_SEEDS` seeds derive no address
+                    if signer.seeds().len() <= MAX_SEEDS {
+                        push(&seeds[..signer.seeds().len()]);
+                    }
+                })
+            }
+        }
+
+        signers
+    }
+
+    /// Return the id of the program deriving the addresses.
+    #[inline(always)]
+    pub fn program_id(&self) -> &'a Pubkey {
+        self.program_id
+    }
+
+    /// Number of signing addresses.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        match self.seeds {
+            SignersSeeds::Slices(seeds) => seeds.len(),
+            SignersSeeds::Signers(signers) => signers.len(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check whether `key` is one of the signing addresses.
+    ///
+    /// Seeds that do not derive a valid address sign nothing.
+    #[inline(always)]
+    pub fn signs(&self, key: &Pubkey) -> bool {
+        self.addresses[..self.addresses_len].contains(key)
+    }
+
+    /// Address of the seeds in the layout passed to `sol_invoke_signed_c`.
+    #[cfg(target_os = "solana")]
+    #[inline(always)]
+    pub(crate) fn as_ptr(&self) -> *const u8 {
+        match self.seeds {
+            // sound because `&[&[u8]]` and `&[u8]` have the layout of
+            // `Signer` and `Seed`, which is checked at compile time above
+            SignersSeeds::Slices(seeds) => seeds.as_ptr() as *const u8,
+            SignersSeeds::Signers(signers) => signers.as_ptr() as *const u8,
+        }
+    }
+
+    /// Copy the seeds of every signing address.
+    #[cfg(all(feature = "mock", not(target_os = "solana")))]
+    pub(crate) fn to_vec(self) -> std::vec::Vec<std::vec::Vec<std::vec::Vec<u8>>> {
+        match self.seeds {
+            SignersSeeds::Slices(signers_seeds) => signers_seeds
+                .iter()
+                .map(|seeds| seeds.iter().map(|seed| seed.to_vec()).collect())
+                .collect(),
+            SignersSeeds::Signers(signers) => signers
+                .iter()
+                .map(|signer| {
+                    signer
+                        .seeds()
+                        .iter()
+                        .map(|seed| seed.as_bytes().to_vec())
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Zero-copy views of borrowed account data.
+
+use core::{marker::PhantomData, ops::Deref};
+
+/// Borrowed account data viewed as a state type.
+///
+/// The data borrow is held for the lifetime of the view.
+pub struct StateRef<G, T> {
+    data: G,
+    _state: PhantomData<T>,
+}
+
+impl<G: Deref<Target = [u8]>, T> StateRef<G, T> {
+    /// # Safety
+    ///
+    /// `T` must have an alignment of 1 and the borrowed data must hold at
+    /// least `size_of::<T>()` bytes that are a valid `T`.
+    #[inline(always)]
+    pub unsafe fn new(data: G) -> Self {
+        Self {
+            data,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<G: Deref<Target = [u8]>, T> Deref for StateRef<G, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { &*(self.data.as_ptr() as *const T) }
+    }
+}
@@ -0,0 +1,378 @@
+use nostd_entrypoint_invoke::reader::Reader;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+/// A system instruction decoded from its bincode-serialized data.
+///
+/// Pubkeys and seeds are borrowed from the instruction data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SystemInstruction<'a> {
+    /// Create a new account.
+    CreateAccount {
+        /// Number of lamports to transfer to the new account.
+        lamports: u64,
+
+        /// Number of bytes of memory to allocate.
+        space: u64,
+
+        /// Address of program that will own the new account.
+        owner: &'a Pubkey,
+    },
+
+    /// Assign account to a program.
+    Assign {
+        /// Owner program account.
+        owner: &'a Pubkey,
+    },
+
+    /// Transfer lamports.
+    Transfer {
+        /// Amount of lamports to transfer.
+        lamports: u64,
+    },
+
+    /// Create a new account at an address derived from a base pubkey and a
+    /// seed.
+    CreateAccountWithSeed {
+        /// Base public key.
+        base: &'a Pubkey,
+
+        /// String of ASCII chars, no longer than `Pubkey::MAX_SEED_LEN`.
+        seed: &'a str,
+
+        /// Number of lamports to transfer to the new account.
+        lamports: u64,
+
+        /// Number of bytes of memory to allocate.
+        space: u64,
+
+        /// Owner program account address.
+        owner: &'a Pubkey,
+    },
+
+    /// Consumes a stored nonce, replacing it with a successor.
+    AdvanceNonceAccount,
+
+    /// Withdraw funds from a nonce account.
+    WithdrawNonceAccount {
+        /// Amount of lamports to withdraw.
+        lamports: u64,
+    },
+
+    /// Drive state of an uninitialized nonce account to initialized, setting
+    /// the nonce value.
+    InitializeNonceAccount {
+        /// Entity authorized to execute nonce instructions on the account.
+        authority: &'a Pubkey,
+    },
+
+    /// Change the entity authorized to execute nonce instructions on the
+    /// account.
+    AuthorizeNonceAccount {
+        /// New entity authorized to execute nonce instructions.
+        authority: &'a Pubkey,
+    },
+
+    /// Allocate space in a (possibly new) account without funding.
+    Allocate {
+        /// Number of bytes of memory to allocate.
+        space: u64,
+    },
+
+    /// Allocate space for and assign an account at an address derived from a
+    /// base public key and a seed.
+    AllocateWithSeed {
+        /// Base public key.
+        base: &'a Pubkey,
+
+        /// String of ASCII chars, no longer than `Pubkey::MAX_SEED_LEN`.
+        seed: &'a str,
+
+        /// Number of bytes of memory to allocate.
+        space: u64,
+
+        /// Owner program account.
+        owner: &'a Pubkey,
+    },
+
+    /// Assign account to a program based on a seed.
+    AssignWithSeed {
+        /// Base public key.
+        base: &'a Pubkey,
+
+        /// String of ASCII chars, no longer than `Pubkey::MAX_SEED_LEN`.
+        seed: &'a str,
+
+        /// Owner program account.
+        owner: &'a Pubkey,
+    },
+
+    /// Transfer lamports from a derived address.
+    TransferWithSeed {
+        /// Amount of lamports to transfer.
+        lamports: u64,
+
+        /// Seed to use to derive the funding account address.
+        from_seed: &'a str,
+
+        /// Owner to use to derive the funding account address.
+        from_owner: &'a Pubkey,
+    },
+
+    /// One-time idempotent upgrade of legacy nonce versions in order to bump
+    /// them out of chain blockhash domain.
+    UpgradeNonceAccount,
+}
+
+impl<'a> SystemInstruction<'a> {
+    /// Decode the data of a system instruction.
+    ///
+    /// Like the system program, trailing bytes are ignored. Returns
+    /// `InvalidInstructionData` if the data is not a valid instruction.
+    pub fn unpack(data: &'a [u8]) -> Result<Self, ProgramError> {
+        Self::read(&mut Reader::new(data)).ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    #[inline(always)]
+    fn read(reader: &mut Reader<'a>) -> Option<Self> {
+        let instruction = match reader.read_u32()? {
+            0 => Self::CreateAccount {
+                lamports: reader.read_u64()?,
+                space: reader.read_u64()?,
+                owner: reader.read_pubkey()?,
+            },
+            1 => Self::Assign {
+                owner: reader.read_pubkey()?,
+            },
+            2 => Self::Transfer {
+                lamports: reader.read_u64()?,
+            },
+            3 => Self::CreateAccountWithSeed {
+                base: reader.read_pubkey()?,
+                seed: reader.read_bincode_str()?,
+                lamports: reader.read_u64()?,
+                space: reader.read_u64()?,
+                owner: reader.read_pubkey()?,
+            },
+            4 => Self::AdvanceNonceAccount,
+            5 => Self::WithdrawNonceAccount {
+                lamports: reader.read_u64()?,
+            },
+            6 => Self::InitializeNonceAccount {
+                authority: reader.read_pubkey()?,
+            },
+            7 => Self::AuthorizeNonceAccount {
+                authority: reader.read_pubkey()?,
+            },
+            8 => Self::Allocate {
+                space: reader.read_u64()?,
+            },
+            9 => Self::AllocateWithSeed {
+                base: reader.read_pubkey()?,
+                seed: reader.read_bincode_str()?,
+                space: reader.read_u64()?,
+                owner: reader.read_pubkey()?,
+            },
+            10 => Self::AssignWithSeed {
+                base: reader.read_pubkey()?,
+                seed: reader.read_bincode_str()?,
+                owner: reader.read_pubkey()?,
+            },
+            11 => Self::TransferWithSeed {
+                lamports: reader.read_u64()?,
+                from_seed: reader.read_bincode_str()?,
+                from_owner: reader.read_pubkey()?,
+            },
+            12 => Self::UpgradeNonceAccount,
+            _ => return None,
+        };
+
+        Some(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use solana_program::system_instruction::SystemInstruction as Native;
+    use std::{string::String, vec::Vec};
+
+    use super::*;
+
+    const MAX_SEED: &str = "0123456789abcdef0123456789abcdef";
+
+    /// Native instructions with their expected decoding.
+    fn instructions<'a>(
+        base: &'a Pubkey,
+        owner: &'a Pubkey,
+        seed: &'a str,
+    ) -> Vec<(Native, SystemInstruction<'a>)> {
+        std::vec![
+            (
+                Native::CreateAccount {
+                    lamports: 1,
+                    space: 2,
+                    owner: *owner,
+                },
+                SystemInstruction::CreateAccount {
+                    lamports: 1,
+                    space: 2,
+                    owner,
+                },
+            ),
+            (
+                Native::Assign { owner: *owner },
+                SystemInstruction::Assign { owner },
+            ),
+            (
+                Native::Transfer { lamports: 3 },
+                SystemInstruction::Transfer { lamports: 3 },
+            ),
+            (
+                Native::CreateAccountWithSeed {
+                    base: *base,
+                    seed: String::from(seed),
+                    lamports: 4,
+                    space: 5,
+                    owner: *owner,
+                },
+                SystemInstruction::CreateAccountWithSeed {
+                    base,
+                    seed,
+                    lamports: 4,
+                    space: 5,
+                    owner,
+                },
+            ),
+            (
+                Native::AdvanceNonceAccount,
+                SystemInstruction::AdvanceNonceAccount,
+            ),
+            (
+                Native::WithdrawNonceAccount(6),
+                SystemInstruction::WithdrawNonceAccount { lamports: 6 },
+            ),
+            (
+                Native::InitializeNonceAccount(*owner),
+                SystemInstruction::InitializeNonceAccount { authority: owner },
+            ),
+            (
+                Native::AuthorizeNonceAccount(*base),
+                SystemInstruction::AuthorizeNonceAccount { authority: base },
+            ),
+            (
+                Native::Allocate { space: 7 },
+                SystemInstruction::Allocate { space: 7 },
+            ),
+            (
+                Native::AllocateWithSeed {
+                    base: *base,
+                    seed: String::from(seed),
+                    space: 8,
+                    owner: *owner,
+                },
+                SystemInstruction::AllocateWithSeed {
+                    base,
+                    seed,
+                    space: 8,
+                    owner,
+                },
+            ),
+            (
+                Native::AssignWithSeed {
+                    base: *base,
+                    seed: String::from(seed),
+                    owner: *owner,
+                },
+                SystemInstruction::AssignWithSeed { base, seed, owner },
+            ),
+            (
+                Native::TransferWithSeed {
+                    lamports: 9,
+                    from_seed: String::from(seed),
+                    from_owner: *owner,
+                },
+                SystemInstruction::TransferWithSeed {
+                    lamports: 9,
+                    from_seed: seed,
+                    from_owner: owner,
+                },
+            ),
+            (
+                Native::UpgradeNonceAccount,
+                SystemInstruction::UpgradeNonceAccount,
+            ),
+        ]
+    }
+
+    #[test]
+    fn unpacks_every_bincode_instruction() {
+        let (base, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        for seed in ["", MAX_SEED] {
+            let instructions = instructions(&base, &owner, seed);
+            assert_eq!(instructions.len(), 13);
+
+            for (native, expected) in instructions {
+                let data = bincode::serialize(&native).unwrap();
+                assert_eq!(SystemInstruction::unpack(&data), Ok(expected));
+
+                // trailing bytes are ignored
+                let mut trailing = data.clone();
+                trailing.extend_from_slice(&[1, 2, 3]);
+                assert_eq!(SystemInstruction::unpack(&trailing), Ok(expected));
+
+                // every truncation is rejected
+                for len in 0..data.len() {
+                    assert_eq!(
+                        SystemInstruction::unpack(&data[..len]),
+                        Err(ProgramError::InvalidInstructionData),
+                        "{native:?} truncated to {len} bytes"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_seeds() {
+        let native = Native::AssignWithSeed {
+            base: Pubkey::new_unique(),
+            seed: String::from("ab"),
+            owner: Pubkey::new_unique(),
+        };
+        let data = bincode::serialize(&native).unwrap();
+        // - [0..4  ]: discriminator
+        // - [4..36 ]: base
+        // - [36..44]: seed length
+        // - [44..46]: seed
+        assert_eq!(&data[44..46], b"ab");
+
+        let mut invalid_utf8 = data.clone();
+        invalid_utf8[44] = 0xff;
+        assert_eq!(
+            SystemInstruction::unpack(&invalid_utf8),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        let mut huge_length = data.clone();
+        huge_length[36..44].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            SystemInstruction::unpack(&huge_length),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_discriminators() {
+        for discriminator in [13u32, u32::MAX] {
+            let mut data = discriminator.to_le_bytes().to_vec();
+            data.extend_from_slice(&[0; 64]);
+
+            assert_eq!(
+                SystemInstruction::unpack(&data),
+                Err(ProgramError::InvalidInstructionData)
+            );
+        }
+    }
+}
@@ -0,0 +1,455 @@
+use nostd_entrypoint_invoke::PdaSigners;
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{
+    entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    system_instruction::SystemError,
+};
+
+use crate::{
+    instructions::{Allocate, Assign, CreateAccount, Transfer, TransferWithSeed},
+    sysvars::Rent,
+};
+
+/// Create a rent-exempt account, even if it already holds lamports.
+///
+/// `CreateAccount` fails when the new account has a non-zero balance, so
+/// anyone can block the creation of a program derived address by sending it
+/// lamports. When the account is pre-funded, this tops it up to the
+/// rent-exempt minimum with `Transfer`, then uses `Allocate` and `Assign`
+/// instead.
+///
+/// An account already owned by `owner` with `space` bytes of data is only
+/// topped up to the rent-exempt minimum, since it may have been allocated and
+/// assigned without being funded. All the instructions are signed with
+/// `signers`, which must include the seeds of the account if it is a program
+/// derived address.
+pub fn create_account_idempotent(
+    payer: &NoStdAccountInfo,
+    account: &NoStdAccountInfo,
+    space: u64,
+    owner: &Pubkey,
+    signers: PdaSigners,
+) -> ProgramResult {
+    let initialized = account.owner() == owner && account.data_len() as u64 == space;
+
+    if !initialized && (*account.owner() != crate::ID || account.data_len() != 0) {
+        return Err(ProgramError::Custom(
+            SystemError::AccountAlreadyInUse as u32,
+        ));
+    }
+
+    let minimum_balance = Rent::get()?.minimum_balance(space as usize);
+    let lamports = *account
+        .try_borrow_lamports()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+
+    if lamports == 0 && !initialized {
+        return CreateAccount {
+            from: payer,
+            to: account,
+            lamports: minimum_balance,
+            space,
+            owner,
+        }
+        .invoke_signed_with(signers);
+    }
+
+    if lamports < minimum_balance {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: minimum_balance - lamports,
+        }
+        .invoke_signed_with(signers)?;
+    }
+
+    if initialized {
+        return Ok(());
+    }
+
+    Allocate { account, space }.invoke_signed_with(signers)?;
+
+    Assign { account, owner }.invoke_signed_with(signers)
+}
+
+/// Create a rent-exempt program derived address owned by `program_id`, even
+/// if it already holds lamports.
+///
+/// `seeds` are the seeds of the address, including the bump seed. See
+/// [`create_account_idempotent`].
+#[inline(always)]
+pub fn init_pda(
+    payer: &NoStdAccountInfo,
+    pda: &NoStdAccountInfo,
+    space: u64,
+    program_id: &Pubkey,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    create_account_idempotent(
+        payer,
+        pda,
+        space,
+        program_id,
+        PdaSigners::new(program_id, &[seeds]),
+    )
+}
+
+/// Close an account, moving all its lamports to `destination`.
+///
+/// The data of a program-owned account is zeroed so the account cannot be
+/// revived with its old state by funding it again in the same transaction.
+/// Without `closed_discriminator` the data is also truncated and the account
+/// is assigned to the system program, as if it had never been created. With
+/// it, the data keeps its length and starts with the discriminator, and the
+/// account stays owned by the program: the runtime only lets a program
+/// reassign an account whose data is zeroed, and the program must own the
+/// tombstone to recognize and reject a revived account. Either way the
+/// runtime removes the account once the transaction succeeds, since it holds
+/// no lamports.
+///
+/// `program_id` is the id of the running program, which must own the
+/// account or `IllegalOwner` is returned. System-owned accounts are closed
+/// with `Transfer` instead, which requires the account to sign the
+/// transaction; see [`close_system_account`] to sign with seeds.
+pub fn close_account(
+    account: &NoStdAccountInfo,
+    destination: &NoStdAccountInfo,
+    program_id: &Pubkey,
+    closed_discriminator: Option<&[u8]>,
+) -> ProgramResult {
+    if account.key() == destination.key() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // the system program owns the account rather than the running program,
+    // and moves its lamports
+    if *account.owner() == crate::ID {
+        return close_system_account(account, destination, PdaSigners::NONE);
+    }
+
+    if account.owner() != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    {
+        let mut data = account
+            .try_borrow_mut_data()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+
+        data.fill(0);
+
+        if let Some(discriminator) = closed_discriminator {
+            data.get_mut(..discriminator.len())
+                .ok_or(ProgramError::AccountDataTooSmall)?
+                .copy_from_slice(discriminator);
+        }
+    }
+
+    if closed_discriminator.is_none() {
+        account.realloc(0, false)?;
+
+        // no reference to the owner is alive, and the runtime accepts the
+        // new owner since the program owns the account and its data is empty
+        unsafe { assign_unchecked(account, &crate::ID) };
+    }
+
+    let mut lamports = account
+        .try_borrow_mut_lamports()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+    let mut destination_lamports = destination
+        .try_borrow_mut_lamports()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+
+    *destination_lamports = destination_lamports
+        .checked_add(*lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    *lamports = 0;
+
+    Ok(())
+}
+
+/// Assign `account` to `owner` by writing the owner in its account header,
+/// without invoking the system program.
+///
+/// The runtime only accepts the new owner if the running program owns the
+/// account, the account is writable and its data is zeroed.
+///
+/// # Safety
+///
+/// No reference to the current owner, such as one returned by
+/// `account.owner()`, may be alive.
+#[inline(always)]
+unsafe fn assign_unchecked(account: &NoStdAccountInfo, owner: &Pubkey) {
+    *(account.to_info_c().owner as *mut Pubkey) = *owner;
+}
+
+/// Close a system-owned account with `Transfer`, moving all its lamports to
+/// `destination`.
+///
+/// The transfer is signed with `signers`, which must include the seeds of
+/// the account if it is a program derived address.
+pub fn close_system_account(
+    account: &NoStdAccountInfo,
+    destination: &NoStdAccountInfo,
+    signers: PdaSigners,
+) -> ProgramResult {
+    let lamports = *account
+        .try_borrow_lamports()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+
+    Transfer {
+        from: account,
+        to: destination,
+        lamports,
+    }
+    .invoke_signed_with(signers)
+}
+
+/// Close a system-owned account derived from a base pubkey and a seed with
+/// `TransferWithSeed`, moving all its lamports to `destination`.
+///
+/// The transfer is signed by `base`, with `signers` if it is a program
+/// derived address.
+pub fn close_system_account_with_seed(
+    account: &NoStdAccountInfo,
+    base: &NoStdAccountInfo,
+    seed: &str,
+    owner: &Pubkey,
+    destination: &NoStdAccountInfo,
+    signers: PdaSigners,
+) -> ProgramResult {
+    let lamports = *account
+        .try_borrow_lamports()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+
+    TransferWithSeed {
+        from: account,
+        base,
+        to: destination,
+        lamports,
+        seed,
+        owner,
+    }
+    .invoke_signed_with(signers)
+}
+
+#[cfg(test)]
+mod tests {
+    use nostd_entrypoint_invoke::{
+        mock::{self, MockAccount},
+        pda::find_program_address,
+    };
+    use solana_program::{rent, sysvar};
+
+    use super::*;
+
+    const SPACE: u64 = 16;
+
+    /// Set the default rent and enable the system program emulation.
+    fn setup() -> u64 {
+        let rent = rent::Rent::default();
+        mock::set_sysvar(&sysvar::rent::ID, Some(&bincode::serialize(&rent).unwrap()));
+        mock::emulate_system_program(true);
+        mock::clear_invoked_instructions();
+
+        rent.minimum_balance(SPACE as usize)
+    }
+
+    fn payer() -> MockAccount {
+        MockAccount::new(Pubkey::new_unique(), crate::ID, 10_000_000, &[])
+            .signer(true)
+            .writable(true)
+    }
+
+    fn account(owner: Pubkey, lamports: u64, data: &[u8]) -> MockAccount {
+        MockAccount::new(Pubkey::new_unique(), owner, lamports, data)
+            .signer(true)
+            .writable(true)
+    }
+
+    #[test]
+    fn creates_new_accounts() {
+        let minimum_balance = setup();
+        let (payer, new_account) = (payer(), account(crate::ID, 0, &[]));
+        let owner = Pubkey::new_unique();
+
+        create_account_idempotent(
+            payer.info(),
+            new_account.info(),
+            SPACE,
+            &owner,
+            PdaSigners::NONE,
+        )
+        .unwrap();
+
+        assert_eq!(new_account.lamports(), minimum_balance);
+        assert_eq!(new_account.owner(), owner);
+        assert_eq!(new_account.data(), [0; SPACE as usize]);
+        assert_eq!(mock::take_invoked_instructions().len(), 1);
+    }
+
+    #[test]
+    fn tops_up_pre_funded_pdas() {
+        let minimum_balance = setup();
+        let program_id = Pubkey::new_unique();
+        let (address, bump) = find_program_address(&[b"pda"], &program_id).unwrap();
+        let bump = [bump];
+
+        let payer = payer();
+        let pda = MockAccount::new(address, crate::ID, 1, &[]).writable(true);
+
+        init_pda(
+            payer.info(),
+            pda.info(),
+            SPACE,
+            &program_id,
+            &[b"pda", &bump],
+        )
+        .unwrap();
+
+        assert_eq!(pda.lamports(), minimum_balance);
+        assert_eq!(pda.owner(), program_id);
+        assert_eq!(pda.data(), [0; SPACE as usize]);
+        // transfer, allocate and assign
+        assert_eq!(mock::take_invoked_instructions().len(), 3);
+    }
+
+    #[test]
+    fn funds_initialized_accounts_below_the_minimum_balance() {
+        let minimum_balance = setup();
+        let owner = Pubkey::new_unique();
+        let payer = payer();
+
+        // allocated and assigned, but not funded
+        let unfunded = account(owner, 0, &[7; SPACE as usize]);
+        create_account_idempotent(
+            payer.info(),
+            unfunded.info(),
+            SPACE,
+            &owner,
+            PdaSigners::NONE,
+        )
+        .unwrap();
+
+        assert_eq!(unfunded.lamports(), minimum_balance);
+        assert_eq!(unfunded.data(), [7; SPACE as usize]);
+        assert_eq!(mock::take_invoked_instructions().len(), 1);
+
+        // rent exempt: nothing to do
+        create_account_idempotent(
+            payer.info(),
+            unfunded.info(),
+            SPACE,
+            &owner,
+            PdaSigners::NONE,
+        )
+        .unwrap();
+
+        assert!(mock::take_invoked_instructions().is_empty());
+    }
+
+    #[test]
+    fn rejects_accounts_in_use() {
+        setup();
+        let payer = payer();
+        let owner = Pubkey::new_unique();
+
+        let in_use = account(owner, 1, &[0; 8]);
+        assert_eq!(
+            create_account_idempotent(payer.info(), in_use.info(), SPACE, &owner, PdaSigners::NONE),
+            Err(ProgramError::Custom(
+                SystemError::AccountAlreadyInUse as u32
+            ))
+        );
+        assert!(mock::take_invoked_instructions().is_empty());
+    }
+
+    #[test]
+    fn closes_program_accounts() {
+        let program_id = Pubkey::new_unique();
+        let destination = account(crate::ID, 5, &[]);
+
+        let closed = account(program_id, 100, &[1; 8]);
+        close_account(closed.info(), destination.info(), &program_id, None).unwrap();
+
+        assert_eq!(closed.lamports(), 0);
+        assert_eq!(destination.lamports(), 105);
+        assert!(closed.data().is_empty());
+        assert_eq!(closed.owner(), crate::ID);
+
+        let tombstone = account(program_id, 100, &[1; 8]);
+        close_account(
+            tombstone.info(),
+            destination.info(),
+            &program_id,
+            Some(&[0xff, 0xfe]),
+        )
+        .unwrap();
+
+        assert_eq!(tombstone.lamports(), 0);
+        assert_eq!(destination.lamports(), 205);
+        assert_eq!(tombstone.data(), [0xff, 0xfe, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(tombstone.owner(), program_id);
+
+        let too_small = account(program_id, 100, &[1]);
+        assert_eq!(
+            close_account(
+                too_small.info(),
+                destination.info(),
+                &program_id,
+                Some(&[0xff, 0xfe])
+            ),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+        assert_eq!(
+            close_account(destination.info(), destination.info(), &program_id, None),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        // an account of another program
+        let foreign = account(Pubkey::new_unique(), 100, &[1; 8]);
+        assert_eq!(
+            close_account(foreign.info(), destination.info(), &program_id, None),
+            Err(ProgramError::IllegalOwner)
+        );
+        assert_eq!(foreign.lamports(), 100);
+        assert_eq!(foreign.data(), [1; 8]);
+    }
+
+    #[test]
+    fn closes_system_accounts_with_transfer() {
+        setup();
+        let destination = account(crate::ID, 5, &[]);
+
+        let closed = account(crate::ID, 100, &[]);
+        close_account(
+            closed.info(),
+            destination.info(),
+            &Pubkey::new_unique(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(closed.lamports(), 0);
+        assert_eq!(destination.lamports(), 105);
+        assert_eq!(mock::take_invoked_instructions().len(), 1);
+    }
+
+    #[test]
+    fn requires_the_rent_sysvar() {
+        setup();
+        mock::set_sysvar(&sysvar::rent::ID, None);
+
+        let (payer, new_account) = (payer(), account(crate::ID, 0, &[]));
+        assert_eq!(
+            create_account_idempotent(
+                payer.info(),
+                new_account.info(),
+                SPACE,
+                &Pubkey::new_unique(),
+                PdaSigners::NONE
+            ),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+}
@@ -1,6 +1,6 @@
-use nostd_entrypoint_invoke::invoke_signed;
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
 use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
-use solana_program::entrypoint::ProgramResult;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
 
 /// Consumes a stored nonce, replacing it with a successor.
 ///
@@ -22,18 +22,26 @@ pub struct AdvanceNonceAccount<'a> {
 impl<'a> AdvanceNonceAccount<'a> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+        self.invoke_signed_with(PdaSigners::NONE)
     }
 
-    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
-        // account metadata
-        let account_metas: [AccountMetaC; 3] = [
-            self.account.to_meta_c(),
-            self.recent_blockhashes_sysvar.to_meta_c(),
-            self.authority.to_meta_c_signer(),
-        ];
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
 
-        let data = &[4];
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let data = self.instruction_data();
 
         // instruction
         let instruction = InstructionC {
@@ -41,13 +49,30 @@ impl<'a> AdvanceNonceAccount<'a> {
             accounts: account_metas.as_ptr(),
             accounts_len: 3,
             data: data.as_ptr(),
-            data_len: 1,
+            data_len: 4,
         };
 
-        invoke_signed(
+        invoke_signed_with(
             &instruction,
             &[self.account, self.recent_blockhashes_sysvar, self.authority],
             signers,
         )
     }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 3] {
+        [
+            self.account.to_meta_c(),
+            self.recent_blockhashes_sysvar.to_meta_c(),
+            self.authority.to_meta_c_signer(),
+        ]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::AdvanceNonceAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 4] {
+        // -  [0..4 ]: instruction discriminator
+        [4, 0, 0, 0]
+    }
 }
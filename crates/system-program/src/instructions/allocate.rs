@@ -1,6 +1,6 @@
-use nostd_entrypoint_invoke::invoke_signed;
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
 use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
-use solana_program::entrypoint::ProgramResult;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
 
 /// Allocate space in a (possibly new) account without funding.
 ///
@@ -17,19 +17,26 @@ pub struct Allocate<'a> {
 impl<'a> Allocate<'a> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+        self.invoke_signed_with(PdaSigners::NONE)
     }
 
-    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
-        // account metadata
-        let account_metas: [AccountMetaC; 1] = [self.account.to_meta_c_signer()];
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
 
-        // instruction data
-        // -  [0..4 ]: instruction discriminator
-        // -  [4..12]: space
-        let mut instruction_data = [0; 12];
-        instruction_data[0] = 8;
-        instruction_data[4..12].copy_from_slice(&self.space.to_le_bytes());
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
 
         let instruction = InstructionC {
             accounts: account_metas.as_ptr(),
@@ -39,6 +46,23 @@ impl<'a> Allocate<'a> {
             program_id: &crate::ID,
         };
 
-        invoke_signed(&instruction, &[self.account], signers)
+        invoke_signed_with(&instruction, &[self.account], signers)
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 1] {
+        [self.account.to_meta_c_signer()]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::Allocate`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 12] {
+        // -  [0..4 ]: instruction discriminator
+        // -  [4..12]: space
+        let mut instruction_data = [0; 12];
+        instruction_data[0] = 8;
+        instruction_data[4..12].copy_from_slice(&self.space.to_le_bytes());
+        instruction_data
     }
 }
@@ -1,4 +1,4 @@
-use nostd_entrypoint_invoke::invoke_signed;
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
 use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
 use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
 
@@ -23,20 +23,26 @@ pub struct AuthorizeNonceAccount<'a, 'b> {
 impl<'a, 'b> AuthorizeNonceAccount<'a, 'b> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+        self.invoke_signed_with(PdaSigners::NONE)
     }
 
-    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
-        // account metadata
-        let account_metas: [AccountMetaC; 2] =
-            [self.account.to_meta_c(), self.authority.to_meta_c_signer()];
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
 
-        // instruction data
-        // -  [0..4 ]: instruction discriminator
-        // -  [4..12]: lamports
-        let mut instruction_data = [0; 36];
-        instruction_data[0] = 7;
-        instruction_data[4..36].copy_from_slice(self.new_authority.as_ref());
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
 
         let instruction = InstructionC {
             accounts: account_metas.as_ptr(),
@@ -46,6 +52,23 @@ impl<'a, 'b> AuthorizeNonceAccount<'a, 'b> {
             program_id: &crate::ID,
         };
 
-        invoke_signed(&instruction, &[self.account, self.authority], signers)
+        invoke_signed_with(&instruction, &[self.account, self.authority], signers)
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 2] {
+        [self.account.to_meta_c(), self.authority.to_meta_c_signer()]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::AuthorizeNonceAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 36] {
+        // -  [0..4 ]: instruction discriminator
+        // -  [4..36]: new authority pubkey
+        let mut instruction_data = [0; 36];
+        instruction_data[0] = 7;
+        instruction_data[4..36].copy_from_slice(self.new_authority.as_ref());
+        instruction_data
     }
 }
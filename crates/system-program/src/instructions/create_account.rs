@@ -1,4 +1,4 @@
-use nostd_entrypoint_invoke::invoke_signed;
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
 use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
 use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
 
@@ -27,24 +27,26 @@ pub struct CreateAccount<'a> {
 impl<'a> CreateAccount<'a> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+        self.invoke_signed_with(PdaSigners::NONE)
     }
 
-    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
-        // account metadata
-        let account_metas: [AccountMetaC; 2] =
-            [self.from.to_meta_c_signer(), self.to.to_meta_c_signer()];
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
 
-        // instruction data
-        // - [0..4  ]: instruction discriminator
-        // - [4..12 ]: lamports
-        // - [12..20]: account space
-        // - [20..52]: owner pubkey
-        let mut instruction_data = [0; 52];
-        // create account instruction has a '0' discriminator
-        instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
-        instruction_data[12..20].copy_from_slice(&self.space.to_le_bytes());
-        instruction_data[20..52].copy_from_slice(self.owner.as_ref());
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
 
         let instruction = InstructionC {
             accounts: account_metas.as_ptr(),
@@ -54,6 +56,27 @@ impl<'a> CreateAccount<'a> {
             program_id: &crate::ID,
         };
 
-        invoke_signed(&instruction, &[self.from, self.to], signers)
+        invoke_signed_with(&instruction, &[self.from, self.to], signers)
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 2] {
+        [self.from.to_meta_c_signer(), self.to.to_meta_c_signer()]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::CreateAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 52] {
+        // - [0..4  ]: instruction discriminator
+        // - [4..12 ]: lamports
+        // - [12..20]: account space
+        // - [20..52]: owner pubkey
+        let mut instruction_data = [0; 52];
+        // create account instruction has a '0' discriminator
+        instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
+        instruction_data[12..20].copy_from_slice(&self.space.to_le_bytes());
+        instruction_data[20..52].copy_from_slice(self.owner.as_ref());
+        instruction_data
     }
 }
@@ -1,6 +1,8 @@
-use nostd_entrypoint_invoke::invoke_signed;
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
 use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
-use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::seed::{validate_seed, verify_with_seed};
 
 /// Create a new account at an address derived from a base pubkey and a seed.
 ///
@@ -8,8 +10,7 @@ use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
 ///   0. `[WRITE, SIGNER]` Funding account
 ///   1. `[WRITE]` Created account
 ///   2. `[SIGNER]` (optional) Base account; the account matching the base Pubkey below must be
-///                          provided as a signer, but may be the same as the funding account
-///             
+///      provided as a signer, but may be the same as the funding account
 pub struct CreateAccountWithSeed<'a, 'b, 'c> {
     /// Funding account.
     pub from: &'a NoStdAccountInfo,
@@ -40,48 +41,119 @@ pub struct CreateAccountWithSeed<'a, 'b, 'c> {
 impl<'a, 'b, 'c> CreateAccountWithSeed<'a, 'b, 'c> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let (instruction_data, data_len) = self.instruction_data()?;
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 3,
+            data: instruction_data.as_ptr(),
+            data_len: data_len as u64,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(
+            &instruction,
+            &[self.from, self.to, self.base.unwrap_or(self.from)],
+            signers,
+        )
+    }
+
+    /// Invoke the instruction after checking the derived address with
+    /// [`Self::verify_address`].
+    #[inline(always)]
+    pub fn invoke_verified(&self) -> ProgramResult {
+        self.verify_address()?;
+        self.invoke()
     }
 
-    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
-        // account metadata
-        let account_metas: [AccountMetaC; 3] = [
+    /// Invoke the instruction with signers after checking the derived
+    /// address with [`Self::verify_address`].
+    pub fn invoke_signed_verified(
+        &self,
+        program_id: &Pubkey,
+        signers: &[&[&[u8]]],
+    ) -> ProgramResult {
+        self.verify_address()?;
+        self.invoke_signed(program_id, signers)
+    }
+
+    /// Invoke the instruction with `Signer` seeds after checking the derived
+    /// address with [`Self::verify_address`].
+    pub fn invoke_signed_with_signers_verified(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.verify_address()?;
+        self.invoke_signed_with_signers(program_id, signers)
+    }
+
+    /// Check that `to` is the address derived from the base, seed and owner.
+    ///
+    /// Returns `InvalidSeeds` if it is not.
+    #[inline(always)]
+    pub fn verify_address(&self) -> ProgramResult {
+        verify_with_seed(
+            self.to.key(),
+            self.base.unwrap_or(self.from).key(),
+            self.seed,
+            self.owner,
+        )
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 3] {
+        [
             self.from.to_meta_c_signer(),
             self.to.to_meta_c(),
             self.base.unwrap_or(self.from).to_meta_c_signer(),
-        ];
+        ]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::CreateAccountWithSeed`.
+    ///
+    /// Returns the data buffer together with the number of bytes in use.
+    pub fn instruction_data(&self) -> Result<([u8; 124], usize), ProgramError> {
+        validate_seed(self.seed)?;
 
-        // instruction data
         // - [0..4  ]: instruction discriminator
         // - [4..36 ]: base pubkey
-        // - [36..40]: seed length
-        // - [40..  ]: seed (max 32)
+        // - [36..44]: seed length
+        // - [44..  ]: seed (max 32)
         // - [..  +8]: lamports
         // - [..  +8]: account space
         // - [.. +32]: owner pubkey
-        let mut instruction_data = [0; 120];
+        let mut instruction_data = [0; 124];
         instruction_data[0] = 3;
         instruction_data[4..36].copy_from_slice(self.base.unwrap_or(self.from).key().as_ref());
-        instruction_data[36..40].copy_from_slice(&u32::to_le_bytes(self.seed.len() as u32));
+        instruction_data[36..44].copy_from_slice(&u64::to_le_bytes(self.seed.len() as u64));
 
-        let offset = 40 + self.seed.len();
-        instruction_data[40..offset].copy_from_slice(self.seed.as_bytes());
+        let offset = 44 + self.seed.len();
+        instruction_data[44..offset].copy_from_slice(self.seed.as_bytes());
         instruction_data[offset..offset + 8].copy_from_slice(&self.lamports.to_le_bytes());
         instruction_data[offset + 8..offset + 16].copy_from_slice(&self.space.to_le_bytes());
         instruction_data[offset + 16..offset + 48].copy_from_slice(self.owner.as_ref());
 
-        let instruction = InstructionC {
-            accounts: account_metas.as_ptr(),
-            accounts_len: 3,
-            data: instruction_data.as_ptr(),
-            data_len: (offset + 48) as u64,
-            program_id: &crate::ID,
-        };
-
-        invoke_signed(
-            &instruction,
-            &[self.from, self.to, self.base.unwrap_or(self.from)],
-            signers,
-        )
+        Ok((instruction_data, offset + 48))
     }
 }
@@ -1,4 +1,4 @@
-use nostd_entrypoint_invoke::invoke_signed;
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
 use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
 use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
 
@@ -34,23 +34,26 @@ pub struct InitializeNonceAccount<'a, 'b> {
 impl<'a, 'b> InitializeNonceAccount<'a, 'b> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+        self.invoke_signed_with(PdaSigners::NONE)
     }
 
-    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
-        // account metadata
-        let account_metas: [AccountMetaC; 3] = [
-            self.account.to_meta_c(),
-            self.recent_blockhashes_sysvar.to_meta_c(),
-            self.rent_sysvar.to_meta_c(),
-        ];
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
 
-        // instruction data
-        // -  [0..4 ]: instruction discriminator
-        // -  [4..36]: authority pubkey
-        let mut instruction_data = [0; 36];
-        instruction_data[0] = 6;
-        instruction_data[4..36].copy_from_slice(self.authority.as_ref());
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
 
         let instruction = InstructionC {
             accounts: account_metas.as_ptr(),
@@ -60,7 +63,7 @@ impl<'a, 'b> InitializeNonceAccount<'a, 'b> {
             program_id: &crate::ID,
         };
 
-        invoke_signed(
+        invoke_signed_with(
             &instruction,
             &[
                 self.account,
@@ -70,4 +73,25 @@ impl<'a, 'b> InitializeNonceAccount<'a, 'b> {
             signers,
         )
     }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 3] {
+        [
+            self.account.to_meta_c(),
+            self.recent_blockhashes_sysvar.to_meta_c(),
+            self.rent_sysvar.to_meta_c(),
+        ]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::InitializeNonceAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 36] {
+        // -  [0..4 ]: instruction discriminator
+        // -  [4..36]: authority pubkey
+        let mut instruction_data = [0; 36];
+        instruction_data[0] = 6;
+        instruction_data[4..36].copy_from_slice(self.authority.as_ref());
+        instruction_data
+    }
 }
@@ -9,6 +9,8 @@ mod create_account_with_seed;
 mod initialize_nonce_account;
 mod transfer;
 mod transfer_with_seed;
+mod upgrade_nonce_account;
+mod withdraw_nonce_account;
 
 pub use advance_nonce_account::*;
 pub use allocate::*;
@@ -21,3 +23,591 @@ pub use create_account_with_seed::*;
 pub use initialize_nonce_account::*;
 pub use transfer::*;
 pub use transfer_with_seed::*;
+pub use upgrade_nonce_account::*;
+pub use withdraw_nonce_account::*;
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::{
+        mock::{self, MockAccount},
+        Seed, Signer,
+    };
+    use solana_program::{
+        entrypoint::ProgramResult, instruction::Instruction, program_error::ProgramError,
+        pubkey::Pubkey, system_instruction, sysvar,
+    };
+
+    use super::*;
+
+    /// Seed of `MAX_SEED_LEN` ASCII chars.
+    const MAX_SEED: &str = "seed-of-thirty-two-ascii-chars!!";
+
+    /// Seed one char longer than `MAX_SEED_LEN`.
+    const LONG_SEED: &str = "seed-of-thirty-three-ascii-chars!";
+
+    /// Seed with a non-ASCII char.
+    const NON_ASCII_SEED: &str = "graine-é";
+
+    fn new_account() -> MockAccount {
+        MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &[])
+    }
+
+    /// Check the seeds accepted by a `*WithSeed` instruction, given the
+    /// length of its data for a seed.
+    fn check_seeds(data_len: impl Fn(&str) -> Result<usize, ProgramError>, fixed_len: usize) {
+        assert_eq!(data_len(MAX_SEED), Ok(fixed_len + MAX_SEED.len()));
+        assert_eq!(data_len(""), Ok(fixed_len));
+        assert_eq!(
+            data_len(LONG_SEED),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+        assert_eq!(data_len(NON_ASCII_SEED), Err(ProgramError::InvalidSeeds));
+    }
+
+    #[test]
+    fn create_account_with_seed_checks_the_seed() {
+        let (from, to, base) = (new_account(), new_account(), new_account());
+        let owner = Pubkey::new_unique();
+
+        check_seeds(
+            |seed| {
+                CreateAccountWithSeed {
+                    from: from.info(),
+                    to: to.info(),
+                    base: Some(base.info()),
+                    seed,
+                    lamports: 1,
+                    space: 2,
+                    owner: &owner,
+                }
+                .instruction_data()
+                .map(|(_, len)| len)
+            },
+            124 - 32,
+        );
+    }
+
+    #[test]
+    fn allocate_with_seed_checks_the_seed() {
+        let (account, base) = (new_account(), new_account());
+        let owner = Pubkey::new_unique();
+
+        check_seeds(
+            |seed| {
+                AllocateWithSeed {
+                    account: account.info(),
+                    base: base.info(),
+                    seed,
+                    space: 2,
+                    owner: &owner,
+                }
+                .instruction_data()
+                .map(|(_, len)| len)
+            },
+            116 - 32,
+        );
+    }
+
+    #[test]
+    fn assign_with_seed_checks_the_seed() {
+        let (account, base) = (new_account(), new_account());
+        let owner = Pubkey::new_unique();
+
+        check_seeds(
+            |seed| {
+                AssignWithSeed {
+                    account: account.info(),
+                    base: base.info(),
+                    seed,
+                    owner: &owner,
+                }
+                .instruction_data()
+                .map(|(_, len)| len)
+            },
+            108 - 32,
+        );
+    }
+
+    #[test]
+    fn transfer_with_seed_checks_the_seed() {
+        let (from, base, to) = (new_account(), new_account(), new_account());
+        let owner = Pubkey::new_unique();
+
+        check_seeds(
+            |seed| {
+                TransferWithSeed {
+                    from: from.info(),
+                    base: base.info(),
+                    to: to.info(),
+                    lamports: 1,
+                    seed,
+                    owner: &owner,
+                }
+                .instruction_data()
+                .map(|(_, len)| len)
+            },
+            84 - 32,
+        );
+    }
+
+    fn writable() -> MockAccount {
+        new_account().writable(true)
+    }
+
+    fn signer() -> MockAccount {
+        new_account().signer(true)
+    }
+
+    fn sysvar(id: Pubkey) -> MockAccount {
+        MockAccount::new(id, sysvar::ID, 1, &[])
+    }
+
+    /// Invoke a builder and return the instruction it invoked.
+    fn invoked(invoke: impl FnOnce() -> ProgramResult) -> Instruction {
+        mock::clear_invoked_instructions();
+        invoke().unwrap();
+
+        let mut invoked = mock::take_invoked_instructions();
+        assert_eq!(invoked.len(), 1);
+        invoked.remove(0).instruction
+    }
+
+    #[test]
+    fn create_account_matches_system_instruction() {
+        let (from, to) = (signer().writable(true), signer().writable(true));
+        let owner = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            CreateAccount {
+                from: from.info(),
+                to: to.info(),
+                lamports: 1,
+                space: 2,
+                owner: &owner,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::create_account(&from.key(), &to.key(), 1, 2, &owner)
+        );
+    }
+
+    #[test]
+    fn assign_matches_system_instruction() {
+        let account = signer().writable(true);
+        let owner = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            Assign {
+                account: account.info(),
+                owner: &owner,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::assign(&account.key(), &owner)
+        );
+    }
+
+    #[test]
+    fn transfer_matches_system_instruction() {
+        let (from, to) = (signer().writable(true), writable());
+
+        let instruction = invoked(|| {
+            Transfer {
+                from: from.info(),
+                to: to.info(),
+                lamports: 3,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::transfer(&from.key(), &to.key(), 3)
+        );
+    }
+
+    #[test]
+    fn create_account_with_seed_matches_system_instruction() {
+        let (from, to, base) = (signer().writable(true), writable(), signer());
+        let owner = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            CreateAccountWithSeed {
+                from: from.info(),
+                to: to.info(),
+                base: Some(base.info()),
+                seed: MAX_SEED,
+                lamports: 1,
+                space: 2,
+                owner: &owner,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::create_account_with_seed(
+                &from.key(),
+                &to.key(),
+                &base.key(),
+                MAX_SEED,
+                1,
+                2,
+                &owner
+            )
+        );
+
+        // without a base, the funding account is the base and its meta is
+        // repeated with its own privileges, which the runtime merges
+        let instruction = invoked(|| {
+            CreateAccountWithSeed {
+                from: from.info(),
+                to: to.info(),
+                base: None,
+                seed: MAX_SEED,
+                lamports: 1,
+                space: 2,
+                owner: &owner,
+            }
+            .invoke()
+        });
+
+        let mut expected = system_instruction::create_account_with_seed(
+            &from.key(),
+            &to.key(),
+            &from.key(),
+            MAX_SEED,
+            1,
+            2,
+            &owner,
+        );
+        expected.accounts[2].is_writable = true;
+        assert_eq!(instruction, expected);
+    }
+
+    #[test]
+    fn advance_nonce_account_matches_system_instruction() {
+        let (account, authority) = (writable(), signer());
+        let recent_blockhashes = sysvar(sysvar::recent_blockhashes::ID);
+
+        let instruction = invoked(|| {
+            AdvanceNonceAccount {
+                account: account.info(),
+                recent_blockhashes_sysvar: recent_blockhashes.info(),
+                authority: authority.info(),
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::advance_nonce_account(&account.key(), &authority.key())
+        );
+    }
+
+    #[test]
+    fn withdraw_nonce_account_matches_system_instruction() {
+        let (account, recipient, authority) = (writable(), writable(), signer());
+        let recent_blockhashes = sysvar(sysvar::recent_blockhashes::ID);
+        let rent = sysvar(sysvar::rent::ID);
+
+        let instruction = invoked(|| {
+            WithdrawNonceAccount {
+                account: account.info(),
+                recipient: recipient.info(),
+                recent_blockhashes_sysvar: recent_blockhashes.info(),
+                rent_sysvar: rent.info(),
+                authority: authority.info(),
+                lamports: 4,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::withdraw_nonce_account(
+                &account.key(),
+                &authority.key(),
+                &recipient.key(),
+                4
+            )
+        );
+    }
+
+    #[test]
+    fn initialize_nonce_account_matches_system_instruction() {
+        let account = writable();
+        let recent_blockhashes = sysvar(sysvar::recent_blockhashes::ID);
+        let rent = sysvar(sysvar::rent::ID);
+        let authority = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            InitializeNonceAccount {
+                account: account.info(),
+                recent_blockhashes_sysvar: recent_blockhashes.info(),
+                rent_sysvar: rent.info(),
+                authority: &authority,
+            }
+            .invoke()
+        });
+
+        // the second instruction of `create_nonce_account` initializes it
+        let create_nonce_account = system_instruction::create_nonce_account(
+            &Pubkey::new_unique(),
+            &account.key(),
+            &authority,
+            1,
+        );
+        assert_eq!(instruction, create_nonce_account[1]);
+    }
+
+    #[test]
+    fn authorize_nonce_account_matches_system_instruction() {
+        let (account, authority) = (writable(), signer());
+        let new_authority = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            AuthorizeNonceAccount {
+                account: account.info(),
+                authority: authority.info(),
+                new_authority: &new_authority,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::authorize_nonce_account(
+                &account.key(),
+                &authority.key(),
+                &new_authority
+            )
+        );
+    }
+
+    #[test]
+    fn allocate_matches_system_instruction() {
+        let account = signer().writable(true);
+
+        let instruction = invoked(|| {
+            Allocate {
+                account: account.info(),
+                space: 5,
+            }
+            .invoke()
+        });
+
+        assert_eq!(instruction, system_instruction::allocate(&account.key(), 5));
+    }
+
+    #[test]
+    fn allocate_with_seed_matches_system_instruction() {
+        let (account, base) = (writable(), signer());
+        let owner = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            AllocateWithSeed {
+                account: account.info(),
+                base: base.info(),
+                seed: MAX_SEED,
+                space: 2,
+                owner: &owner,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::allocate_with_seed(
+                &account.key(),
+                &base.key(),
+                MAX_SEED,
+                2,
+                &owner
+            )
+        );
+    }
+
+    #[test]
+    fn assign_with_seed_matches_system_instruction() {
+        let (account, base) = (writable(), signer());
+        let owner = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            AssignWithSeed {
+                account: account.info(),
+                base: base.info(),
+                seed: MAX_SEED,
+                owner: &owner,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::assign_with_seed(&account.key(), &base.key(), MAX_SEED, &owner)
+        );
+    }
+
+    #[test]
+    fn transfer_with_seed_matches_system_instruction() {
+        let (from, base, to) = (writable(), signer(), writable());
+        let owner = Pubkey::new_unique();
+
+        let instruction = invoked(|| {
+            TransferWithSeed {
+                from: from.info(),
+                base: base.info(),
+                to: to.info(),
+                lamports: 6,
+                seed: MAX_SEED,
+                owner: &owner,
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::transfer_with_seed(
+                &from.key(),
+                &base.key(),
+                MAX_SEED.into(),
+                &owner,
+                &to.key(),
+                6
+            )
+        );
+    }
+
+    #[test]
+    fn upgrade_nonce_account_matches_system_instruction() {
+        let account = writable();
+
+        let instruction = invoked(|| {
+            UpgradeNonceAccount {
+                account: account.info(),
+            }
+            .invoke()
+        });
+
+        assert_eq!(
+            instruction,
+            system_instruction::upgrade_nonce_account(account.key())
+        );
+    }
+
+    /// Check the verified entry points of a `*WithSeed` instruction.
+    ///
+    /// `invoke` builds the instruction for a base, an owner and an account
+    /// that may or may not be derived from them with `MAX_SEED`, then calls
+    /// the entry point of the given index with `invoke_verified!`.
+    fn check_verified_invocations(
+        invoke: impl Fn(&MockAccount, &Pubkey, &MockAccount, usize) -> ProgramResult,
+    ) {
+        let signer = |key| {
+            MockAccount::new(key, crate::ID, 0, &[])
+                .signer(true)
+                .writable(true)
+        };
+        let base = signer(Pubkey::new_unique());
+        let owner = Pubkey::new_unique();
+        let derived = signer(Pubkey::create_with_seed(&base.key(), MAX_SEED, &owner).unwrap());
+        let other = signer(Pubkey::new_unique());
+
+        for entry_point in 0..3 {
+            mock::clear_invoked_instructions();
+
+            assert_eq!(
+                invoke(&base, &owner, &other, entry_point),
+                Err(ProgramError::InvalidSeeds)
+            );
+            assert!(mock::take_invoked_instructions().is_empty());
+
+            assert_eq!(invoke(&base, &owner, &derived, entry_point), Ok(()));
+            assert_eq!(mock::take_invoked_instructions().len(), 1);
+        }
+    }
+
+    /// Call `invoke_verified`, `invoke_signed_verified` or
+    /// `invoke_signed_with_signers_verified`, by index.
+    macro_rules! invoke_verified {
+        ($instruction:expr, $entry_point:expr) => {{
+            let program_id = Pubkey::new_unique();
+            let bump = [255];
+            let seeds = [Seed::from(&bump)];
+            let signers = [Signer::new(&seeds).unwrap()];
+            let instruction = $instruction;
+
+            match $entry_point {
+                0 => instruction.invoke_verified(),
+                1 => instruction.invoke_signed_verified(&program_id, &[&[&bump]]),
+                _ => instruction.invoke_signed_with_signers_verified(&program_id, &signers),
+            }
+        }};
+    }
+
+    #[test]
+    fn verified_invocations_check_the_derived_address() {
+        let payer = new_account().signer(true).writable(true);
+        let to = new_account().writable(true);
+
+        check_verified_invocations(|base, owner, account, entry_point| {
+            invoke_verified!(
+                CreateAccountWithSeed {
+                    from: payer.info(),
+                    to: account.info(),
+                    base: Some(base.info()),
+                    seed: MAX_SEED,
+                    lamports: 1,
+                    space: 2,
+                    owner,
+                },
+                entry_point
+            )
+        });
+        check_verified_invocations(|base, owner, account, entry_point| {
+            invoke_verified!(
+                AllocateWithSeed {
+                    account: account.info(),
+                    base: base.info(),
+                    seed: MAX_SEED,
+                    space: 2,
+                    owner,
+                },
+                entry_point
+            )
+        });
+        check_verified_invocations(|base, owner, account, entry_point| {
+            invoke_verified!(
+                AssignWithSeed {
+                    account: account.info(),
+                    base: base.info(),
+                    seed: MAX_SEED,
+                    owner,
+                },
+                entry_point
+            )
+        });
+        check_verified_invocations(|base, owner, account, entry_point| {
+            invoke_verified!(
+                TransferWithSeed {
+                    from: account.info(),
+                    base: base.info(),
+                    to: to.info(),
+                    lamports: 1,
+                    seed: MAX_SEED,
+                    owner,
+                },
+                entry_point
+            )
+        });
+    }
+}
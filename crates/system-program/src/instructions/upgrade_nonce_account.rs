@@ -0,0 +1,63 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// One-time idempotent upgrade of legacy nonce versions in order to bump
+/// them out of chain blockhash domain.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Nonce account
+pub struct UpgradeNonceAccount<'a> {
+    /// Nonce account.
+    pub account: &'a NoStdAccountInfo,
+}
+
+impl<'a> UpgradeNonceAccount<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let data = self.instruction_data();
+
+        // instruction
+        let instruction = InstructionC {
+            program_id: &crate::ID,
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: data.as_ptr(),
+            data_len: 4,
+        };
+
+        invoke_signed_with(&instruction, &[self.account], signers)
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 1] {
+        [self.account.to_meta_c()]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::UpgradeNonceAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 4] {
+        // -  [0..4 ]: instruction discriminator
+        [12, 0, 0, 0]
+    }
+}
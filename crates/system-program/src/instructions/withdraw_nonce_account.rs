@@ -0,0 +1,106 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Withdraw funds from a nonce account.
+///
+/// The `u64` parameter is the lamports to withdraw, which must leave the
+/// account balance above the rent exempt reserve or at zero.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Nonce account
+///   1. `[WRITE]` Recipient account
+///   2. `[]` RecentBlockhashes sysvar
+///   3. `[]` Rent sysvar
+///   4. `[SIGNER]` Nonce authority
+pub struct WithdrawNonceAccount<'a> {
+    /// Nonce account.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Recipient account.
+    pub recipient: &'a NoStdAccountInfo,
+
+    /// RecentBlockhashes sysvar.
+    pub recent_blockhashes_sysvar: &'a NoStdAccountInfo,
+
+    /// Rent sysvar.
+    pub rent_sysvar: &'a NoStdAccountInfo,
+
+    /// Nonce authority.
+    pub authority: &'a NoStdAccountInfo,
+
+    /// Lamports to withdraw.
+    ///
+    /// The account balance must be left above the rent exempt reserve
+    /// or at zero.
+    pub lamports: u64,
+}
+
+impl<'a> WithdrawNonceAccount<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let account_metas = self.account_metas();
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 5,
+            data: instruction_data.as_ptr(),
+            data_len: 12,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(
+            &instruction,
+            &[
+                self.account,
+                self.recipient,
+                self.recent_blockhashes_sysvar,
+                self.rent_sysvar,
+                self.authority,
+            ],
+            signers,
+        )
+    }
+
+    /// Account metadata of the instruction.
+    #[inline(always)]
+    pub fn account_metas(&self) -> [AccountMetaC; 5] {
+        [
+            self.account.to_meta_c(),
+            self.recipient.to_meta_c(),
+            self.recent_blockhashes_sysvar.to_meta_c(),
+            self.rent_sysvar.to_meta_c(),
+            self.authority.to_meta_c_signer(),
+        ]
+    }
+
+    /// Instruction data, as serialized by `SystemInstruction::WithdrawNonceAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 12] {
+        // -  [0..4 ]: instruction discriminator
+        // -  [4..12]: lamports
+        let mut instruction_data = [0; 12];
+        instruction_data[0] = 5;
+        instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
+        instruction_data
+    }
+}
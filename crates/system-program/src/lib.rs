@@ -2,6 +2,11 @@
 
 use solana_nostd_entrypoint::solana_program::declare_id;
 
+pub mod decode;
+pub mod helpers;
 pub mod instructions;
+pub mod seed;
+pub mod state;
+pub mod sysvars;
 
 declare_id!("11111111111111111111111111111111");
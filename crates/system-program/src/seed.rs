@@ -0,0 +1,118 @@
+use nostd_entrypoint_invoke::pda::PDA_MARKER;
+use solana_program::{
+    entrypoint::ProgramResult,
+    hash::hashv,
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEED_LEN},
+};
+
+/// Check that a seed is accepted by the `*WithSeed` instructions.
+///
+/// The seed must be a string of ASCII chars no longer than `MAX_SEED_LEN`.
+#[inline(always)]
+pub fn validate_seed(seed: &str) -> Result<(), ProgramError> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    if !seed.is_ascii() {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
+
+/// Derive the address of a `*WithSeed` account from its base, seed and
+/// owner, like `Pubkey::create_with_seed`.
+///
+/// On-chain the address is hashed with the `sol_sha256` syscall.
+#[inline(always)]
+pub fn derive_with_seed(base: &Pubkey, seed: &str, owner: &Pubkey) -> Result<Pubkey, ProgramError> {
+    validate_seed(seed)?;
+
+    // derived addresses must not collide with program derived addresses
+    if owner.as_ref().ends_with(PDA_MARKER) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    Ok(Pubkey::new_from_array(
+        hashv(&[base.as_ref(), seed.as_bytes(), owner.as_ref()]).to_bytes(),
+    ))
+}
+
+/// Check that `address` is derived from the given base, seed and owner.
+///
+/// Returns `InvalidSeeds` if it is not.
+#[inline(always)]
+pub fn verify_with_seed(
+    address: &Pubkey,
+    base: &Pubkey,
+    seed: &str,
+    owner: &Pubkey,
+) -> ProgramResult {
+    if derive_with_seed(base, seed, owner)? == *address {
+        Ok(())
+    } else {
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_with_seed_matches_create_with_seed() {
+        let base = Pubkey::new_unique();
+
+        for owner in [
+            crate::ID,
+            Pubkey::new_unique(),
+            Pubkey::new_from_array([0xff; 32]),
+        ] {
+            for seed in ["", "a", "seed", "seed-of-thirty-two-ascii-chars!!"] {
+                assert_eq!(
+                    derive_with_seed(&base, seed, &owner),
+                    Ok(Pubkey::create_with_seed(&base, seed, &owner).unwrap())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn derive_with_seed_rejects_invalid_inputs() {
+        let base = Pubkey::new_unique();
+
+        let mut pda_owner = [7; 32];
+        pda_owner[32 - PDA_MARKER.len()..].copy_from_slice(PDA_MARKER);
+        assert_eq!(
+            derive_with_seed(&base, "seed", &Pubkey::new_from_array(pda_owner)),
+            Err(ProgramError::IllegalOwner)
+        );
+
+        assert_eq!(
+            derive_with_seed(&base, "seed-of-thirty-three-ascii-chars!", &crate::ID),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+        assert_eq!(
+            derive_with_seed(&base, "graine-é", &crate::ID),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn verify_with_seed_checks_the_address() {
+        let (base, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let address = Pubkey::create_with_seed(&base, "seed", &owner).unwrap();
+
+        assert_eq!(verify_with_seed(&address, &base, "seed", &owner), Ok(()));
+        assert_eq!(
+            verify_with_seed(&address, &base, "other", &owner),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            verify_with_seed(&address, &owner, "seed", &base),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+}
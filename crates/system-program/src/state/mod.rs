@@ -0,0 +1,3 @@
+mod nonce;
+
+pub use nonce::*;
@@ -0,0 +1,231 @@
+use core::ops::Deref;
+
+use nostd_entrypoint_invoke::state::StateRef;
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{hash::Hash, program_error::ProgramError, pubkey::Pubkey};
+
+/// Version of a nonce account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonceVersion {
+    /// The durable nonce is the blockhash itself.
+    Legacy,
+
+    /// The durable nonce is a hash of the blockhash, so durable nonce and
+    /// blockhash domains are separated.
+    Current,
+}
+
+/// Zero-copy view of a nonce account.
+///
+/// The layout matches the 80 bytes of a bincode-serialized
+/// `nonce::state::Versions`.
+#[repr(C)]
+pub struct Nonce {
+    /// `Versions` discriminator.
+    version: [u8; 4],
+
+    /// `State` discriminator.
+    state: [u8; 4],
+
+    /// Data of an initialized nonce account.
+    data: NonceData,
+}
+
+/// Data of an initialized nonce account.
+#[repr(C)]
+pub struct NonceData {
+    /// Address of the account that signs transactions using the nonce
+    /// account.
+    authority: Pubkey,
+
+    /// Durable nonce value derived from a valid previous blockhash.
+    durable_nonce: Hash,
+
+    /// Lamports per signature of the fee calculator associated with the
+    /// blockhash.
+    lamports_per_signature: [u8; 8],
+}
+
+const _: () = assert!(core::mem::size_of::<Nonce>() == Nonce::LEN);
+const _: () = assert!(core::mem::align_of::<Nonce>() == 1);
+
+impl Nonce {
+    /// Length of the nonce account data.
+    pub const LEN: usize = 80;
+
+    /// Borrow the data of a nonce account owned by the system program.
+    pub fn from_account_info(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = Nonce> + '_, ProgramError> {
+        if *account_info.owner() != crate::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let data = account_info
+            .try_borrow_data()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+
+        Self::from_bytes(&data)?;
+
+        Ok(unsafe { StateRef::<_, Nonce>::new(data) })
+    }
+
+    /// View the bytes of a nonce account.
+    ///
+    /// The account may be uninitialized, in which case it has no
+    /// [`NonceData`].
+    pub fn from_bytes(data: &[u8]) -> Result<&Nonce, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let nonce = unsafe { &*(data.as_ptr() as *const Nonce) };
+
+        if u32::from_le_bytes(nonce.version) > 1 || u32::from_le_bytes(nonce.state) > 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(nonce)
+    }
+
+    #[inline(always)]
+    pub fn version(&self) -> NonceVersion {
+        if self.version[0] == 0 {
+            NonceVersion::Legacy
+        } else {
+            NonceVersion::Current
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self.state[0] == 1
+    }
+
+    /// Data of the nonce account, or `None` if it is uninitialized.
+    #[inline(always)]
+    pub fn data(&self) -> Option<&NonceData> {
+        self.is_initialized().then_some(&self.data)
+    }
+}
+
+impl NonceData {
+    #[inline(always)]
+    pub fn authority(&self) -> &Pubkey {
+        &self.authority
+    }
+
+    #[inline(always)]
+    pub fn durable_nonce(&self) -> &Hash {
+        &self.durable_nonce
+    }
+
+    #[inline(always)]
+    pub fn lamports_per_signature(&self) -> u64 {
+        u64::from_le_bytes(self.lamports_per_signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::MockAccount;
+    use solana_program::{
+        hash::hash,
+        nonce::state::{Data, DurableNonce, State, Versions},
+    };
+    use std::{boxed::Box, vec::Vec};
+
+    use super::*;
+
+    /// Serialize nonce versions into the data of a nonce account.
+    fn serialize(versions: Versions) -> Vec<u8> {
+        let mut data = bincode::serialize(&versions).unwrap();
+        data.resize(Nonce::LEN, 0);
+        data
+    }
+
+    fn initialized() -> (Pubkey, Data) {
+        let authority = Pubkey::new_unique();
+        let durable_nonce = DurableNonce::from_blockhash(&hash(b"blockhash"));
+
+        (authority, Data::new(authority, durable_nonce, 5_000))
+    }
+
+    #[test]
+    fn reads_every_version_and_state() {
+        let (authority, data) = initialized();
+
+        for (versions, version) in [
+            (
+                Versions::Legacy(Box::new(State::Initialized(data.clone()))),
+                NonceVersion::Legacy,
+            ),
+            (
+                Versions::Current(Box::new(State::Initialized(data.clone()))),
+                NonceVersion::Current,
+            ),
+        ] {
+            let bytes = serialize(versions);
+            let nonce = Nonce::from_bytes(&bytes).unwrap();
+
+            assert_eq!(nonce.version(), version);
+            assert!(nonce.is_initialized());
+
+            let nonce_data = nonce.data().unwrap();
+            assert_eq!(*nonce_data.authority(), authority);
+            assert_eq!(*nonce_data.durable_nonce(), data.blockhash());
+            assert_eq!(nonce_data.lamports_per_signature(), 5_000);
+        }
+
+        for (versions, version) in [
+            (
+                Versions::Legacy(Box::new(State::Uninitialized)),
+                NonceVersion::Legacy,
+            ),
+            (
+                Versions::Current(Box::new(State::Uninitialized)),
+                NonceVersion::Current,
+            ),
+        ] {
+            let bytes = serialize(versions);
+            let nonce = Nonce::from_bytes(&bytes).unwrap();
+
+            assert_eq!(nonce.version(), version);
+            assert!(!nonce.is_initialized());
+            assert!(nonce.data().is_none());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_nonce_accounts() {
+        let bytes = serialize(Versions::new(State::Initialized(initialized().1)));
+
+        assert_eq!(
+            Nonce::from_bytes(&bytes[..Nonce::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // unknown version and state discriminators
+        for offset in [0, 4] {
+            let mut invalid = bytes.clone();
+            invalid[offset] = 2;
+            assert_eq!(
+                Nonce::from_bytes(&invalid).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+        }
+
+        let nonce = MockAccount::new(Pubkey::new_unique(), crate::ID, 1, &bytes);
+        assert!(Nonce::from_account_info(nonce.info())
+            .unwrap()
+            .is_initialized());
+
+        let foreign = MockAccount::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, &bytes);
+        assert_eq!(
+            Nonce::from_account_info(foreign.info()).err(),
+            Some(ProgramError::IncorrectProgramId)
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{
+    clock::{Epoch, Slot, UnixTimestamp},
+    program_error::ProgramError,
+    sysvar::clock::ID,
+};
+
+use super::{borrow_sysvar, get_sysvar, sysvar_from_bytes};
+
+/// Zero-copy view of the Clock sysvar.
+#[repr(C)]
+pub struct Clock {
+    /// The current slot.
+    slot: [u8; 8],
+
+    /// The timestamp of the first slot in this epoch.
+    epoch_start_timestamp: [u8; 8],
+
+    /// The current epoch.
+    epoch: [u8; 8],
+
+    /// The future epoch for which the leader schedule has most recently been
+    /// calculated.
+    leader_schedule_epoch: [u8; 8],
+
+    /// The approximate real world time of the current slot.
+    unix_timestamp: [u8; 8],
+}
+
+const _: () = assert!(core::mem::size_of::<Clock>() == Clock::LEN);
+const _: () = assert!(core::mem::align_of::<Clock>() == 1);
+
+impl Clock {
+    /// Length of the sysvar data.
+    pub const LEN: usize = 40;
+
+    /// Read the sysvar with the `sol_get_clock_sysvar` syscall.
+    #[inline(always)]
+    pub fn get() -> Result<Clock, ProgramError> {
+        let words = get_sysvar!(sol_get_clock_sysvar, ID, 5)?;
+
+        Ok(Clock {
+            slot: words[0].to_le_bytes(),
+            epoch_start_timestamp: words[1].to_le_bytes(),
+            epoch: words[2].to_le_bytes(),
+            leader_schedule_epoch: words[3].to_le_bytes(),
+            unix_timestamp: words[4].to_le_bytes(),
+        })
+    }
+
+    /// Borrow the data of the sysvar account.
+    pub fn from_account_info(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = Clock> + '_, ProgramError> {
+        unsafe { borrow_sysvar(account_info, &ID, Self::LEN) }
+    }
+
+    /// View the bytes of the sysvar.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<&Clock, ProgramError> {
+        unsafe { sysvar_from_bytes(data, Self::LEN) }
+    }
+
+    #[inline(always)]
+    pub fn slot(&self) -> Slot {
+        u64::from_le_bytes(self.slot)
+    }
+
+    #[inline(always)]
+    pub fn epoch_start_timestamp(&self) -> UnixTimestamp {
+        i64::from_le_bytes(self.epoch_start_timestamp)
+    }
+
+    #[inline(always)]
+    pub fn epoch(&self) -> Epoch {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    #[inline(always)]
+    pub fn leader_schedule_epoch(&self) -> Epoch {
+        u64::from_le_bytes(self.leader_schedule_epoch)
+    }
+
+    #[inline(always)]
+    pub fn unix_timestamp(&self) -> UnixTimestamp {
+        i64::from_le_bytes(self.unix_timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::{self, MockAccount};
+    use solana_program::{clock, pubkey::Pubkey};
+    use std::vec::Vec;
+
+    use super::*;
+
+    fn native() -> clock::Clock {
+        clock::Clock {
+            slot: 250_000_000,
+            epoch_start_timestamp: -1_700_000_000,
+            epoch: 580,
+            leader_schedule_epoch: 581,
+            unix_timestamp: 1_700_123_456,
+        }
+    }
+
+    fn check(clock: &Clock, native: &clock::Clock) {
+        assert_eq!(clock.slot(), native.slot);
+        assert_eq!(clock.epoch_start_timestamp(), native.epoch_start_timestamp);
+        assert_eq!(clock.epoch(), native.epoch);
+        assert_eq!(clock.leader_schedule_epoch(), native.leader_schedule_epoch);
+        assert_eq!(clock.unix_timestamp(), native.unix_timestamp);
+    }
+
+    #[test]
+    fn views_match_bincode() {
+        let native = native();
+        let data = bincode::serialize(&native).unwrap();
+        assert_eq!(data.len(), Clock::LEN);
+
+        check(Clock::from_bytes(&data).unwrap(), &native);
+
+        let account = MockAccount::new(ID, Pubkey::default(), 1, &data);
+        check(&Clock::from_account_info(account.info()).unwrap(), &native);
+    }
+
+    #[test]
+    fn rejects_other_lengths_and_keys() {
+        let data = bincode::serialize(&native()).unwrap();
+
+        assert_eq!(
+            Clock::from_bytes(&data[..Clock::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Clock::from_bytes(&[0; Clock::LEN + 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let short = MockAccount::new(ID, Pubkey::default(), 1, &data[..Clock::LEN - 1]);
+        assert_eq!(
+            Clock::from_account_info(short.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let other = MockAccount::new(Pubkey::new_unique(), Pubkey::default(), 1, &data);
+        assert_eq!(
+            Clock::from_account_info(other.info()).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn get_reads_the_in_memory_layout() {
+        let native = native();
+
+        // 5 words, without padding
+        let mut data = Vec::new();
+        data.extend(native.slot.to_le_bytes());
+        data.extend(native.epoch_start_timestamp.to_le_bytes());
+        data.extend(native.epoch.to_le_bytes());
+        data.extend(native.leader_schedule_epoch.to_le_bytes());
+        data.extend(native.unix_timestamp.to_le_bytes());
+        mock::set_sysvar(&ID, Some(&data));
+
+        check(&Clock::get().unwrap(), &native);
+
+        mock::set_sysvar(&ID, None);
+        assert_eq!(Clock::get().err(), Some(ProgramError::UnsupportedSysvar));
+    }
+}
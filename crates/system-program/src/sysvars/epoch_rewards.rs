@@ -0,0 +1,160 @@
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{hash::Hash, program_error::ProgramError, sysvar::epoch_rewards::ID};
+
+use super::{borrow_sysvar, get_sysvar, sysvar_from_bytes};
+
+/// Zero-copy view of the EpochRewards sysvar.
+///
+/// The layout is the one of partitioned epoch rewards. The sysvar account
+/// holds the 81 bytes of the bincode-serialized sysvar.
+#[repr(C)]
+pub struct EpochRewards {
+    /// The starting block height of the rewards distribution in the current
+    /// epoch.
+    distribution_starting_block_height: [u8; 8],
+
+    /// Number of partitions in the rewards distribution in the current epoch.
+    num_partitions: [u8; 8],
+
+    /// The blockhash of the parent block of the first block in the epoch.
+    parent_blockhash: Hash,
+
+    /// The total rewards points calculated for the current epoch, i.e. the
+    /// sum of delegated stake times credits observed for all delegations.
+    total_points: [u8; 16],
+
+    /// The total rewards calculated for the current epoch, in lamports.
+    total_rewards: [u8; 8],
+
+    /// The rewards currently distributed for the current epoch, in lamports.
+    distributed_rewards: [u8; 8],
+
+    /// Whether the rewards period, including calculation and distribution,
+    /// is active.
+    active: u8,
+}
+
+const _: () = assert!(core::mem::size_of::<EpochRewards>() == EpochRewards::LEN);
+const _: () = assert!(core::mem::align_of::<EpochRewards>() == 1);
+
+impl EpochRewards {
+    /// Length of the sysvar data.
+    pub const LEN: usize = 81;
+
+    /// Read the sysvar with the `sol_get_epoch_rewards_sysvar` syscall.
+    #[inline(always)]
+    pub fn get() -> Result<EpochRewards, ProgramError> {
+        // the native type is 16-byte aligned, so `active` is followed by
+        // padding up to 96 bytes
+        let words = get_sysvar!(sol_get_epoch_rewards_sysvar, ID, 12)?;
+
+        let mut parent_blockhash = [0; 32];
+        for (bytes, word) in parent_blockhash.chunks_exact_mut(8).zip(&words[2..6]) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+
+        let mut total_points = [0; 16];
+        total_points[..8].copy_from_slice(&words[6].to_le_bytes());
+        total_points[8..].copy_from_slice(&words[7].to_le_bytes());
+
+        Ok(EpochRewards {
+            distribution_starting_block_height: words[0].to_le_bytes(),
+            num_partitions: words[1].to_le_bytes(),
+            parent_blockhash: Hash::new_from_array(parent_blockhash),
+            total_points,
+            total_rewards: words[8].to_le_bytes(),
+            distributed_rewards: words[9].to_le_bytes(),
+            active: words[10].to_le_bytes()[0],
+        })
+    }
+
+    /// Borrow the data of the sysvar account.
+    pub fn from_account_info(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = EpochRewards> + '_, ProgramError> {
+        unsafe { borrow_sysvar(account_info, &ID, Self::LEN) }
+    }
+
+    /// View the bytes of the sysvar.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<&EpochRewards, ProgramError> {
+        unsafe { sysvar_from_bytes(data, Self::LEN) }
+    }
+
+    #[inline(always)]
+    pub fn distribution_starting_block_height(&self) -> u64 {
+        u64::from_le_bytes(self.distribution_starting_block_height)
+    }
+
+    #[inline(always)]
+    pub fn num_partitions(&self) -> u64 {
+        u64::from_le_bytes(self.num_partitions)
+    }
+
+    #[inline(always)]
+    pub fn parent_blockhash(&self) -> &Hash {
+        &self.parent_blockhash
+    }
+
+    #[inline(always)]
+    pub fn total_points(&self) -> u128 {
+        u128::from_le_bytes(self.total_points)
+    }
+
+    #[inline(always)]
+    pub fn total_rewards(&self) -> u64 {
+        u64::from_le_bytes(self.total_rewards)
+    }
+
+    #[inline(always)]
+    pub fn distributed_rewards(&self) -> u64 {
+        u64::from_le_bytes(self.distributed_rewards)
+    }
+
+    #[inline(always)]
+    pub fn active(&self) -> bool {
+        self.active != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_partitioned_layout() {
+        let mut data = [0; EpochRewards::LEN];
+        data[0..8].copy_from_slice(&1_000u64.to_le_bytes());
+        data[8..16].copy_from_slice(&4u64.to_le_bytes());
+        data[16..48].copy_from_slice(&[7; 32]);
+        data[48..64].copy_from_slice(&(u64::MAX as u128 + 5).to_le_bytes());
+        data[64..72].copy_from_slice(&500u64.to_le_bytes());
+        data[72..80].copy_from_slice(&200u64.to_le_bytes());
+        data[80] = 1;
+
+        let rewards = EpochRewards::from_bytes(&data).unwrap();
+
+        assert_eq!(rewards.distribution_starting_block_height(), 1_000);
+        assert_eq!(rewards.num_partitions(), 4);
+        assert_eq!(rewards.parent_blockhash(), &Hash::new_from_array([7; 32]));
+        assert_eq!(rewards.total_points(), u64::MAX as u128 + 5);
+        assert_eq!(rewards.total_rewards(), 500);
+        assert_eq!(rewards.distributed_rewards(), 200);
+        assert!(rewards.active());
+    }
+
+    #[test]
+    fn rejects_other_lengths() {
+        // the 24 bytes of the layout before partitioned rewards
+        assert_eq!(
+            EpochRewards::from_bytes(&[0; 24]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            EpochRewards::from_bytes(&[0; EpochRewards::LEN + 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
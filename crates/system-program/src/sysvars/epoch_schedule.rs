@@ -0,0 +1,194 @@
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{
+    clock::{Epoch, Slot},
+    program_error::ProgramError,
+    sysvar::epoch_schedule::ID,
+};
+
+use super::{borrow_sysvar, get_sysvar, sysvar_from_bytes};
+
+/// Zero-copy view of the EpochSchedule sysvar.
+#[repr(C)]
+pub struct EpochSchedule {
+    /// The maximum number of slots in each epoch.
+    slots_per_epoch: [u8; 8],
+
+    /// A number of slots before beginning of an epoch to calculate a leader
+    /// schedule for that epoch.
+    leader_schedule_slot_offset: [u8; 8],
+
+    /// Whether epochs start short and grow.
+    warmup: u8,
+
+    /// The first epoch after the warmup period.
+    first_normal_epoch: [u8; 8],
+
+    /// The first slot after the warmup period.
+    first_normal_slot: [u8; 8],
+}
+
+const _: () = assert!(core::mem::size_of::<EpochSchedule>() == EpochSchedule::LEN);
+const _: () = assert!(core::mem::align_of::<EpochSchedule>() == 1);
+
+impl EpochSchedule {
+    /// Length of the sysvar data.
+    pub const LEN: usize = 33;
+
+    /// Read the sysvar with the `sol_get_epoch_schedule_sysvar` syscall.
+    #[inline(always)]
+    pub fn get() -> Result<EpochSchedule, ProgramError> {
+        let words = get_sysvar!(sol_get_epoch_schedule_sysvar, ID, 5)?;
+
+        Ok(EpochSchedule {
+            slots_per_epoch: words[0].to_le_bytes(),
+            leader_schedule_slot_offset: words[1].to_le_bytes(),
+            warmup: words[2] as u8,
+            first_normal_epoch: words[3].to_le_bytes(),
+            first_normal_slot: words[4].to_le_bytes(),
+        })
+    }
+
+    /// Borrow the data of the sysvar account.
+    pub fn from_account_info(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = EpochSchedule> + '_, ProgramError> {
+        unsafe { borrow_sysvar(account_info, &ID, Self::LEN) }
+    }
+
+    /// View the bytes of the sysvar.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<&EpochSchedule, ProgramError> {
+        unsafe { sysvar_from_bytes(data, Self::LEN) }
+    }
+
+    #[inline(always)]
+    pub fn slots_per_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.slots_per_epoch)
+    }
+
+    #[inline(always)]
+    pub fn leader_schedule_slot_offset(&self) -> u64 {
+        u64::from_le_bytes(self.leader_schedule_slot_offset)
+    }
+
+    #[inline(always)]
+    pub fn warmup(&self) -> bool {
+        self.warmup != 0
+    }
+
+    #[inline(always)]
+    pub fn first_normal_epoch(&self) -> Epoch {
+        u64::from_le_bytes(self.first_normal_epoch)
+    }
+
+    #[inline(always)]
+    pub fn first_normal_slot(&self) -> Slot {
+        u64::from_le_bytes(self.first_normal_slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::{self, MockAccount};
+    use solana_program::{epoch_schedule, pubkey::Pubkey};
+    use std::vec::Vec;
+
+    use super::*;
+
+    fn schedules() -> [epoch_schedule::EpochSchedule; 2] {
+        [
+            epoch_schedule::EpochSchedule::default(),
+            epoch_schedule::EpochSchedule::custom(8_192, 4_096, false),
+        ]
+    }
+
+    fn check(schedule: &EpochSchedule, native: &epoch_schedule::EpochSchedule) {
+        assert_eq!(schedule.slots_per_epoch(), native.slots_per_epoch);
+        assert_eq!(
+            schedule.leader_schedule_slot_offset(),
+            native.leader_schedule_slot_offset
+        );
+        assert_eq!(schedule.warmup(), native.warmup);
+        assert_eq!(schedule.first_normal_epoch(), native.first_normal_epoch);
+        assert_eq!(schedule.first_normal_slot(), native.first_normal_slot);
+    }
+
+    #[test]
+    fn views_match_bincode() {
+        for native in schedules() {
+            let data = bincode::serialize(&native).unwrap();
+            assert_eq!(data.len(), EpochSchedule::LEN);
+
+            check(EpochSchedule::from_bytes(&data).unwrap(), &native);
+
+            let account = MockAccount::new(ID, Pubkey::default(), 1, &data);
+            check(
+                &EpochSchedule::from_account_info(account.info()).unwrap(),
+                &native,
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_lengths_and_keys() {
+        let data = bincode::serialize(&epoch_schedule::EpochSchedule::default()).unwrap();
+
+        assert_eq!(
+            EpochSchedule::from_bytes(&data[..EpochSchedule::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        // the in-memory length is not the account length
+        assert_eq!(
+            EpochSchedule::from_bytes(&[0; 40]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let short = MockAccount::new(ID, Pubkey::default(), 1, &data[..EpochSchedule::LEN - 1]);
+        assert_eq!(
+            EpochSchedule::from_account_info(short.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let other = MockAccount::new(Pubkey::new_unique(), Pubkey::default(), 1, &data);
+        assert_eq!(
+            EpochSchedule::from_account_info(other.info()).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn get_reads_the_in_memory_layout() {
+        for native in schedules() {
+            // 5 words: the warmup flag is followed by 7 bytes of padding,
+            // which may hold anything
+            let mut data = Vec::new();
+            data.extend(native.slots_per_epoch.to_le_bytes());
+            data.extend(native.leader_schedule_slot_offset.to_le_bytes());
+            data.extend([
+                native.warmup as u8,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+            ]);
+            data.extend(native.first_normal_epoch.to_le_bytes());
+            data.extend(native.first_normal_slot.to_le_bytes());
+            mock::set_sysvar(&ID, Some(&data));
+
+            check(&EpochSchedule::get().unwrap(), &native);
+        }
+
+        mock::set_sysvar(&ID, None);
+        assert_eq!(
+            EpochSchedule::get().err(),
+            Some(ProgramError::UnsupportedSysvar)
+        );
+    }
+}
@@ -0,0 +1,356 @@
+//! Zero-copy reader of the Instructions sysvar.
+//!
+//! The sysvar holds the instructions of the running transaction:
+//!
+//! - `u16` number of instructions `N`
+//! - `N` x `u16` offsets of the instructions
+//! - for each instruction:
+//!   - `u16` number of accounts `M`
+//!   - `M` x (`u8` flags, `[u8; 32]` pubkey)
+//!   - `[u8; 32]` program id
+//!   - `u16` data length and the data
+//! - `u16` index of the running instruction, at the end of the data
+
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, sysvar::instructions::ID};
+
+/// Flag of a signer account.
+const IS_SIGNER: u8 = 1 << 0;
+
+/// Flag of a writable account.
+const IS_WRITABLE: u8 = 1 << 1;
+
+/// Length of a serialized account meta.
+const ACCOUNT_META_LEN: usize = 1 + 32;
+
+/// Borrow the data of the Instructions sysvar account.
+///
+/// Returns `InvalidArgument` if the account is not the Instructions sysvar,
+/// like the other sysvar readers.
+#[inline(always)]
+pub fn borrow_instructions_sysvar(
+    account_info: &NoStdAccountInfo,
+) -> Result<impl Deref<Target = [u8]> + '_, ProgramError> {
+    super::borrow_sysvar_data(account_info, &ID)
+}
+
+/// Load the index of the running instruction in the transaction.
+///
+/// `data` is the Instructions sysvar account data.
+#[inline(always)]
+pub fn load_current_index(data: &[u8]) -> Result<u16, ProgramError> {
+    let index = data
+        .len()
+        .checked_sub(2)
+        .map(|offset| &data[offset..])
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok(u16::from_le_bytes([index[0], index[1]]))
+}
+
+/// Load the instruction at `index` in the transaction.
+///
+/// `data` is the Instructions sysvar account data. Returns `InvalidArgument`
+/// if there is no such instruction.
+pub fn load_instruction_at(
+    index: usize,
+    data: &[u8],
+) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+    let num_instructions = read_u16(data, 0)? as usize;
+
+    if index >= num_instructions {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut offset = read_u16(data, 2 + 2 * index)? as usize;
+
+    let num_accounts = read_u16(data, offset)? as usize;
+    offset += 2;
+
+    let accounts = read_bytes(data, offset, num_accounts * ACCOUNT_META_LEN)?;
+    offset += accounts.len();
+
+    let program_id = read_bytes(data, offset, 32)?;
+    offset += 32;
+
+    let data_len = read_u16(data, offset)? as usize;
+    offset += 2;
+
+    Ok(IntrospectedInstruction {
+        accounts,
+        program_id: unsafe { &*(program_id.as_ptr() as *const Pubkey) },
+        data: read_bytes(data, offset, data_len)?,
+    })
+}
+
+/// Load an instruction relative to the running instruction, e.g. `-1` for
+/// the previous one.
+///
+/// `data` is the Instructions sysvar account data. Returns `InvalidArgument`
+/// if there is no such instruction.
+pub fn get_instruction_relative(
+    relative_index: i64,
+    data: &[u8],
+) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+    let index = (load_current_index(data)? as i64)
+        .checked_add(relative_index)
+        .filter(|index| *index >= 0)
+        .ok_or(ProgramError::InvalidArgument)?;
+
+    load_instruction_at(index as usize, data)
+}
+
+/// An instruction of the running transaction, borrowed from the Instructions
+/// sysvar data.
+#[derive(Clone, Copy, Debug)]
+pub struct IntrospectedInstruction<'a> {
+    /// Serialized account metas.
+    accounts: &'a [u8],
+
+    /// Program invoked by the instruction.
+    program_id: &'a Pubkey,
+
+    /// Instruction data.
+    data: &'a [u8],
+}
+
+impl<'a> IntrospectedInstruction<'a> {
+    #[inline(always)]
+    pub fn program_id(&self) -> &'a Pubkey {
+        self.program_id
+    }
+
+    #[inline(always)]
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    #[inline(always)]
+    pub fn num_accounts(&self) -> usize {
+        self.accounts.len() / ACCOUNT_META_LEN
+    }
+
+    /// Account meta at `index`, or `None` if there is no such account.
+    #[inline(always)]
+    pub fn account(&self, index: usize) -> Option<IntrospectedAccountMeta<'a>> {
+        self.accounts
+            .get(index * ACCOUNT_META_LEN..(index + 1) * ACCOUNT_META_LEN)
+            .map(IntrospectedAccountMeta::from_bytes)
+    }
+
+    /// Iterate over the account metas of the instruction.
+    #[inline(always)]
+    pub fn accounts(&self) -> impl Iterator<Item = IntrospectedAccountMeta<'a>> {
+        self.accounts
+            .chunks_exact(ACCOUNT_META_LEN)
+            .map(IntrospectedAccountMeta::from_bytes)
+    }
+}
+
+/// Account meta of an instruction of the running transaction.
+#[derive(Clone, Copy, Debug)]
+pub struct IntrospectedAccountMeta<'a> {
+    /// Account pubkey.
+    pub key: &'a Pubkey,
+
+    /// Is `true` if the instruction requires a signature for the account.
+    pub is_signer: bool,
+
+    /// Is `true` if the account data or metadata may be mutated.
+    pub is_writable: bool,
+}
+
+impl<'a> IntrospectedAccountMeta<'a> {
+    /// The bytes must hold a flags byte followed by the pubkey.
+    #[inline(always)]
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self {
+            key: unsafe { &*(bytes[1..].as_ptr() as *const Pubkey) },
+            is_signer: bytes[0] & IS_SIGNER != 0,
+            is_writable: bytes[0] & IS_WRITABLE != 0,
+        }
+    }
+}
+
+#[inline(always)]
+fn read_bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8], ProgramError> {
+    data.get(offset..offset + len)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+#[inline(always)]
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, ProgramError> {
+    let bytes = read_bytes(data, offset, 2)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::MockAccount;
+    use solana_program::sysvar::instructions::{
+        construct_instructions_data, store_current_index, BorrowedAccountMeta, BorrowedInstruction,
+    };
+    use std::{vec, vec::Vec};
+
+    use super::*;
+
+    struct Fixture {
+        program_ids: [Pubkey; 3],
+        keys: [Pubkey; 2],
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                program_ids: [
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                ],
+                keys: [Pubkey::new_unique(), Pubkey::new_unique()],
+            }
+        }
+
+        /// Sysvar data of three instructions, the second one running.
+        fn data(&self) -> Vec<u8> {
+            let instructions = [
+                BorrowedInstruction {
+                    program_id: &self.program_ids[0],
+                    accounts: vec![
+                        BorrowedAccountMeta {
+                            pubkey: &self.keys[0],
+                            is_signer: true,
+                            is_writable: true,
+                        },
+                        BorrowedAccountMeta {
+                            pubkey: &self.keys[1],
+                            is_signer: false,
+                            is_writable: false,
+                        },
+                    ],
+                    data: &[1, 2, 3],
+                },
+                BorrowedInstruction {
+                    program_id: &self.program_ids[1],
+                    accounts: vec![],
+                    data: &[],
+                },
+                BorrowedInstruction {
+                    program_id: &self.program_ids[2],
+                    accounts: vec![BorrowedAccountMeta {
+                        pubkey: &self.keys[1],
+                        is_signer: true,
+                        is_writable: false,
+                    }],
+                    data: &[4; 300],
+                },
+            ];
+
+            let mut data = construct_instructions_data(&instructions);
+            store_current_index(&mut data, 1);
+            data
+        }
+    }
+
+    #[test]
+    fn loads_the_current_index() {
+        let fixture = Fixture::new();
+        let mut data = fixture.data();
+        assert_eq!(load_current_index(&data), Ok(1));
+
+        store_current_index(&mut data, 2);
+        assert_eq!(load_current_index(&data), Ok(2));
+
+        assert_eq!(
+            load_current_index(&[0]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn loads_instructions_by_index() {
+        let fixture = Fixture::new();
+        let data = fixture.data();
+
+        let first = load_instruction_at(0, &data).unwrap();
+        assert_eq!(*first.program_id(), fixture.program_ids[0]);
+        assert_eq!(first.data(), [1, 2, 3]);
+        assert_eq!(first.num_accounts(), 2);
+
+        let account = first.account(0).unwrap();
+        assert_eq!(
+            (*account.key, account.is_signer, account.is_writable),
+            (fixture.keys[0], true, true)
+        );
+        let account = first.account(1).unwrap();
+        assert_eq!(
+            (*account.key, account.is_signer, account.is_writable),
+            (fixture.keys[1], false, false)
+        );
+        assert!(first.account(2).is_none());
+        assert_eq!(first.accounts().count(), 2);
+
+        let last = load_instruction_at(2, &data).unwrap();
+        assert_eq!(*last.program_id(), fixture.program_ids[2]);
+        assert_eq!(last.data(), [4; 300]);
+        let account = last.account(0).unwrap();
+        assert_eq!(
+            (*account.key, account.is_signer, account.is_writable),
+            (fixture.keys[1], true, false)
+        );
+
+        assert_eq!(
+            load_instruction_at(3, &data).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+
+        // the data of the last instruction is cut
+        assert_eq!(
+            load_instruction_at(2, &data[..data.len() - 4]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn loads_instructions_relative_to_the_current_one() {
+        let fixture = Fixture::new();
+        let data = fixture.data();
+
+        for (relative_index, program_id) in [(-1, 0), (0, 1), (1, 2)] {
+            assert_eq!(
+                *get_instruction_relative(relative_index, &data)
+                    .unwrap()
+                    .program_id(),
+                fixture.program_ids[program_id]
+            );
+        }
+
+        for relative_index in [-2, 2, i64::MIN, i64::MAX] {
+            assert_eq!(
+                get_instruction_relative(relative_index, &data).err(),
+                Some(ProgramError::InvalidArgument)
+            );
+        }
+    }
+
+    #[test]
+    fn borrows_only_the_instructions_sysvar() {
+        let data = Fixture::new().data();
+
+        let sysvar = MockAccount::new(ID, Pubkey::default(), 0, &data);
+        assert_eq!(
+            *borrow_instructions_sysvar(sysvar.info()).unwrap(),
+            data[..]
+        );
+
+        let other = MockAccount::new(Pubkey::new_unique(), Pubkey::default(), 0, &data);
+        assert_eq!(
+            borrow_instructions_sysvar(other.info()).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+    }
+}
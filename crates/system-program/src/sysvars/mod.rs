@@ -0,0 +1,419 @@
+mod clock;
+mod epoch_rewards;
+mod epoch_schedule;
+mod rent;
+mod slot_hashes;
+mod stake_history;
+
+pub mod instructions;
+
+pub use clock::*;
+pub use epoch_rewards::*;
+pub use epoch_schedule::*;
+pub use rent::*;
+pub use slot_hashes::*;
+pub use stake_history::*;
+
+use core::{marker::PhantomData, ops::Deref};
+
+use nostd_entrypoint_invoke::state::StateRef;
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+/// Borrow the data of a sysvar account viewed as `T`.
+///
+/// Returns `InvalidArgument` if the account is not the sysvar `id`, and
+/// `InvalidAccountData` if its data is not `len` bytes long.
+///
+/// # Safety
+///
+/// `T` must have an alignment of 1, a size of `len` and every bit pattern
+/// must be a valid value.
+#[inline(always)]
+unsafe fn borrow_sysvar<'a, T: 'a>(
+    account_info: &'a NoStdAccountInfo,
+    id: &Pubkey,
+    len: usize,
+) -> Result<impl Deref<Target = T> + 'a, ProgramError> {
+    if account_info.key() != id {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let data = account_info
+        .try_borrow_data()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+
+    if data.len() != len {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(StateRef::<_, T>::new(data))
+}
+
+/// Borrow the data of a sysvar account.
+///
+/// Returns `InvalidArgument` if the account is not the sysvar `id`.
+#[inline(always)]
+fn borrow_sysvar_data<'a>(
+    account_info: &'a NoStdAccountInfo,
+    id: &Pubkey,
+) -> Result<impl Deref<Target = [u8]> + 'a, ProgramError> {
+    if account_info.key() != id {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    account_info
+        .try_borrow_data()
+        .ok_or(ProgramError::AccountBorrowFailed)
+}
+
+/// View the bytes of a sysvar as `T`.
+///
+/// Returns `InvalidAccountData` if `data` is not `len` bytes long.
+///
+/// # Safety
+///
+/// `T` must have an alignment of 1, a size of `len` and every bit pattern
+/// must be a valid value.
+#[inline(always)]
+unsafe fn sysvar_from_bytes<T>(data: &[u8], len: usize) -> Result<&T, ProgramError> {
+    if data.len() != len {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(&*(data.as_ptr() as *const T))
+}
+
+/// Read a sysvar with one of the `sol_get_*_sysvar` syscalls.
+///
+/// The syscall writes the in-memory layout of the native sysvar type, so the
+/// words are read into a buffer of `N` words aligned like that type. Host
+/// builds with the `mock` feature read the data set with
+/// `nostd_entrypoint_invoke::mock::set_sysvar` for the sysvar `id` instead.
+macro_rules! get_sysvar {
+    ($syscall:ident, $id:expr, $words:literal) => {{
+        #[cfg(target_os = "solana")]
+        {
+            let mut buffer = $crate::sysvars::SysvarBuffer([0u64; $words]);
+
+            let result =
+                unsafe { solana_program::syscalls::$syscall(buffer.0.as_mut_ptr() as *mut u8) };
+
+            if result == solana_program::entrypoint::SUCCESS {
+                Ok(buffer.0)
+            } else {
+                Err(ProgramError::from(result))
+            }
+        }
+
+        #[cfg(all(any(test, feature = "mock"), not(target_os = "solana")))]
+        {
+            $crate::sysvars::mock_sysvar::<$words>(&$id)
+        }
+
+        #[cfg(all(not(any(test, feature = "mock")), not(target_os = "solana")))]
+        {
+            Err::<[u64; $words], _>(ProgramError::UnsupportedSysvar)
+        }
+    }};
+}
+
+use get_sysvar;
+
+/// Read the words of a sysvar set with `mock::set_sysvar`.
+///
+/// Returns `UnsupportedSysvar` if the sysvar is not set, like a cluster that
+/// does not provide it.
+#[cfg(all(any(test, feature = "mock"), not(target_os = "solana")))]
+fn mock_sysvar<const N: usize>(id: &Pubkey) -> Result<[u64; N], ProgramError> {
+    let data = nostd_entrypoint_invoke::mock::sysvar(id).ok_or(ProgramError::UnsupportedSysvar)?;
+    let mut words = [0u64; N];
+
+    for (word, bytes) in words.iter_mut().zip(data.chunks(8)) {
+        let mut word_bytes = [0; 8];
+        word_bytes[..bytes.len()].copy_from_slice(bytes);
+        *word = u64::from_le_bytes(word_bytes);
+    }
+
+    Ok(words)
+}
+
+/// Buffer of a sysvar read by a syscall.
+///
+/// The runtime checks that the buffer is aligned like the native sysvar
+/// type, which is 16 bytes for `EpochRewards`.
+#[cfg(target_os = "solana")]
+#[repr(C, align(16))]
+struct SysvarBuffer<const WORDS: usize>([u64; WORDS]);
+
+/// Find the index of `key` among `len` entries sorted by descending keys.
+#[inline(always)]
+fn search_descending<E>(
+    len: usize,
+    key: u64,
+    key_at: impl Fn(usize) -> Result<u64, E>,
+) -> Result<Option<usize>, E> {
+    let (mut low, mut high) = (0, len);
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let middle_key = key_at(middle)?;
+
+        if middle_key == key {
+            return Ok(Some(middle));
+        } else if middle_key > key {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    Ok(None)
+}
+
+/// Read `data.len()` bytes of a sysvar at `offset` with the `sol_get_sysvar`
+/// syscall, without loading the whole sysvar.
+///
+/// The syscall is not available on every cluster, so it is only used with
+/// the `get-sysvar` feature.
+#[cfg(feature = "get-sysvar")]
+#[inline(always)]
+fn get_sysvar_bytes(id: &Pubkey, data: &mut [u8], offset: usize) -> Result<(), ProgramError> {
+    #[cfg(target_os = "solana")]
+    {
+        extern "C" {
+            fn sol_get_sysvar(
+                sysvar_id_addr: *const u8,
+                var_addr: *mut u8,
+                offset: u64,
+                length: u64,
+            ) -> u64;
+        }
+
+        let result = unsafe {
+            sol_get_sysvar(
+                id as *const _ as *const u8,
+                data.as_mut_ptr(),
+                offset as u64,
+                data.len() as u64,
+            )
+        };
+
+        if result == solana_program::entrypoint::SUCCESS {
+            Ok(())
+        } else {
+            Err(ProgramError::UnsupportedSysvar)
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::hint::black_box((id, data, offset));
+        Err(ProgramError::UnsupportedSysvar)
+    }
+}
+
+/// Find the entry of `key` in a sysvar holding a bincode-serialized
+/// `Vec<(u64, [u8; N])>` sorted by descending key, reading only the bytes it
+/// needs.
+///
+/// `read` fills its buffer with the bytes of the sysvar at an offset, like
+/// [`get_sysvar_bytes`].
+#[cfg(any(feature = "get-sysvar", test))]
+fn find_entry_descending<const N: usize>(
+    key: u64,
+    read: impl Fn(&mut [u8], usize) -> Result<(), ProgramError>,
+) -> Result<Option<[u8; N]>, ProgramError> {
+    let read_u64 = |offset| -> Result<u64, ProgramError> {
+        let mut bytes = [0; 8];
+        read(&mut bytes, offset)?;
+        Ok(u64::from_le_bytes(bytes))
+    };
+
+    let entry_len = 8 + N;
+    let len = read_u64(0)? as usize;
+
+    let index = search_descending(len, key, |index| read_u64(8 + index * entry_len))?;
+
+    index
+        .map(|index| {
+            let mut value = [0; N];
+            read(&mut value, 8 + index * entry_len + 8)?;
+            Ok(value)
+        })
+        .transpose()
+}
+
+/// Zero-copy view of a sysvar holding a bincode-serialized `Vec<(u64, T)>`
+/// sorted by descending key, like SlotHashes and StakeHistory. Lookups
+/// binary-search the entries in place.
+#[derive(Clone, Copy, Debug)]
+struct SortedEntries<'a, T> {
+    /// Serialized entries.
+    entries: &'a [u8],
+
+    value: PhantomData<&'a T>,
+}
+
+impl<'a, T> SortedEntries<'a, T> {
+    /// Length of a `(u64, T)` entry.
+    const ENTRY_LEN: usize = 8 + core::mem::size_of::<T>();
+
+    /// View the bytes of the sysvar.
+    ///
+    /// Returns `InvalidAccountData` if `data` is shorter than its entries.
+    ///
+    /// # Safety
+    ///
+    /// `T` must have an alignment of 1 and every bit pattern must be a valid
+    /// value.
+    #[inline(always)]
+    unsafe fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let len = read_u64(data, 0).ok_or(ProgramError::InvalidAccountData)? as usize;
+
+        let entries = len
+            .checked_mul(Self::ENTRY_LEN)
+            .and_then(|entries_len| data.get(8..8 + entries_len))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            entries,
+            value: PhantomData,
+        })
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.entries.len() / Self::ENTRY_LEN
+    }
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline(always)]
+    fn entry(&self, index: usize) -> Option<(u64, &'a T)> {
+        let entry = self
+            .entries
+            .get(index * Self::ENTRY_LEN..(index + 1) * Self::ENTRY_LEN)?;
+
+        // `from_bytes` requires every bit pattern of `T` to be valid
+        Some((read_u64(entry, 0)?, unsafe {
+            &*(entry[8..].as_ptr() as *const T)
+        }))
+    }
+
+    #[inline(always)]
+    fn position(&self, key: u64) -> Option<usize> {
+        search_descending(self.len(), key, |index| {
+            read_u64(self.entries, index * Self::ENTRY_LEN).ok_or(())
+        })
+        .ok()
+        .flatten()
+    }
+
+    #[inline(always)]
+    fn get(&self, key: u64) -> Option<&'a T> {
+        self.position(key)
+            .and_then(|index| self.entry(index))
+            .map(|(_, value)| value)
+    }
+}
+
+/// Read the little-endian `u64` at `offset` of borrowed sysvar data.
+#[inline(always)]
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset + 8)?;
+    let mut value = [0; 8];
+    value.copy_from_slice(bytes);
+    Some(u64::from_le_bytes(value))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+
+    /// Serialize `(key, value)` entries like bincode serializes a `Vec`.
+    fn serialize(entries: &[(u64, [u8; 4])]) -> Vec<u8> {
+        let mut data = (entries.len() as u64).to_le_bytes().to_vec();
+        for (key, value) in entries {
+            data.extend_from_slice(&key.to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    /// Read the bytes of `data` at an offset, failing past its end like the
+    /// `sol_get_sysvar` syscall.
+    fn reader(data: &[u8]) -> impl Fn(&mut [u8], usize) -> Result<(), ProgramError> + '_ {
+        |buffer, offset| {
+            let bytes = data
+                .get(offset..offset + buffer.len())
+                .ok_or(ProgramError::UnsupportedSysvar)?;
+            buffer.copy_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn search_descending_finds_every_key() {
+        let keys = [50, 40, 30, 20, 10];
+        let key_at = |index: usize| Ok::<_, ()>(keys[index]);
+
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(search_descending(keys.len(), *key, key_at), Ok(Some(index)));
+        }
+
+        for key in [0, 5, 15, 45, 55, u64::MAX] {
+            assert_eq!(search_descending(keys.len(), key, key_at), Ok(None));
+        }
+
+        assert_eq!(search_descending(0, 10, key_at), Ok(None));
+    }
+
+    #[test]
+    fn search_descending_forwards_errors() {
+        assert_eq!(search_descending(3, 10, |_| Err::<u64, _>(())), Err(()));
+    }
+
+    #[test]
+    fn find_entry_reads_the_entry_offsets() {
+        let data = serialize(&[(9, [1; 4]), (7, [2; 4]), (4, [3; 4]), (2, [4; 4])]);
+
+        assert_eq!(find_entry_descending(9, reader(&data)), Ok(Some([1; 4])));
+        assert_eq!(find_entry_descending(7, reader(&data)), Ok(Some([2; 4])));
+        assert_eq!(find_entry_descending(2, reader(&data)), Ok(Some([4; 4])));
+        assert_eq!(find_entry_descending::<4>(8, reader(&data)), Ok(None));
+        assert_eq!(find_entry_descending::<4>(10, reader(&data)), Ok(None));
+        assert_eq!(find_entry_descending::<4>(1, reader(&data)), Ok(None));
+    }
+
+    #[test]
+    fn find_entry_in_an_empty_sysvar() {
+        let data = serialize(&[]);
+
+        assert_eq!(find_entry_descending::<4>(0, reader(&data)), Ok(None));
+    }
+
+    #[test]
+    fn find_entry_in_a_truncated_sysvar() {
+        let data = serialize(&[(9, [1; 4]), (7, [2; 4])]);
+
+        // the value of the last entry is cut off
+        assert_eq!(
+            find_entry_descending::<4>(7, reader(&data[..data.len() - 1])),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+
+        // the length is cut off
+        assert_eq!(
+            find_entry_descending::<4>(7, reader(&data[..4])),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+}
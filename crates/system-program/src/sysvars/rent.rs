@@ -0,0 +1,214 @@
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{program_error::ProgramError, sysvar::rent::ID};
+
+use super::{borrow_sysvar, get_sysvar, sysvar_from_bytes};
+
+/// Account storage overhead for calculation of base rent, i.e. the number of
+/// bytes required to store an account with no data.
+pub const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+
+/// Default exemption threshold, in years.
+const DEFAULT_EXEMPTION_THRESHOLD: f64 = 2.0;
+
+/// Zero-copy view of the Rent sysvar.
+#[repr(C)]
+pub struct Rent {
+    /// Rental rate in lamports/byte-year.
+    lamports_per_byte_year: [u8; 8],
+
+    /// Amount of time (in years) a balance must include rent for the account
+    /// to be rent exempt.
+    exemption_threshold: [u8; 8],
+
+    /// The percentage of collected rent that is burned.
+    burn_percent: u8,
+}
+
+const _: () = assert!(core::mem::size_of::<Rent>() == Rent::LEN);
+const _: () = assert!(core::mem::align_of::<Rent>() == 1);
+
+impl Rent {
+    /// Length of the sysvar data.
+    pub const LEN: usize = 17;
+
+    /// Read the sysvar with the `sol_get_rent_sysvar` syscall.
+    #[inline(always)]
+    pub fn get() -> Result<Rent, ProgramError> {
+        let words = get_sysvar!(sol_get_rent_sysvar, ID, 3)?;
+
+        Ok(Rent {
+            lamports_per_byte_year: words[0].to_le_bytes(),
+            exemption_threshold: words[1].to_le_bytes(),
+            burn_percent: words[2] as u8,
+        })
+    }
+
+    /// Borrow the data of the sysvar account.
+    pub fn from_account_info(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = Rent> + '_, ProgramError> {
+        unsafe { borrow_sysvar(account_info, &ID, Self::LEN) }
+    }
+
+    /// View the bytes of the sysvar.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<&Rent, ProgramError> {
+        unsafe { sysvar_from_bytes(data, Self::LEN) }
+    }
+
+    #[inline(always)]
+    pub fn lamports_per_byte_year(&self) -> u64 {
+        u64::from_le_bytes(self.lamports_per_byte_year)
+    }
+
+    #[inline(always)]
+    pub fn exemption_threshold(&self) -> f64 {
+        f64::from_le_bytes(self.exemption_threshold)
+    }
+
+    #[inline(always)]
+    pub fn burn_percent(&self) -> u8 {
+        self.burn_percent
+    }
+
+    /// Minimum balance for an account with `space` bytes of data to be rent
+    /// exempt.
+    ///
+    /// Floating point operations are skipped with the default exemption
+    /// threshold.
+    #[inline(always)]
+    pub fn minimum_balance(&self, space: usize) -> u64 {
+        let lamports = (ACCOUNT_STORAGE_OVERHEAD + space as u64) * self.lamports_per_byte_year();
+
+        if self.exemption_threshold == DEFAULT_EXEMPTION_THRESHOLD.to_le_bytes() {
+            lamports * DEFAULT_EXEMPTION_THRESHOLD as u64
+        } else {
+            (lamports as f64 * self.exemption_threshold()) as u64
+        }
+    }
+
+    /// Check whether an account with `lamports` and `space` bytes of data is
+    /// rent exempt.
+    #[inline(always)]
+    pub fn is_exempt(&self, lamports: u64, space: usize) -> bool {
+        lamports >= self.minimum_balance(space)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::{self, MockAccount};
+    use solana_program::{pubkey::Pubkey, rent};
+    use std::vec::Vec;
+
+    use super::*;
+
+    fn rents() -> [rent::Rent; 3] {
+        [
+            rent::Rent::default(),
+            rent::Rent {
+                lamports_per_byte_year: 1_234,
+                exemption_threshold: 1.5,
+                burn_percent: 100,
+            },
+            rent::Rent {
+                lamports_per_byte_year: 7,
+                exemption_threshold: 3.7,
+                burn_percent: 0,
+            },
+        ]
+    }
+
+    fn check(rent: &Rent, native: &rent::Rent) {
+        assert_eq!(rent.lamports_per_byte_year(), native.lamports_per_byte_year);
+        assert_eq!(rent.exemption_threshold(), native.exemption_threshold);
+        assert_eq!(rent.burn_percent(), native.burn_percent);
+    }
+
+    #[test]
+    fn views_match_bincode() {
+        for native in rents() {
+            let data = bincode::serialize(&native).unwrap();
+            assert_eq!(data.len(), Rent::LEN);
+
+            check(Rent::from_bytes(&data).unwrap(), &native);
+
+            let account = MockAccount::new(ID, Pubkey::default(), 1, &data);
+            check(&Rent::from_account_info(account.info()).unwrap(), &native);
+        }
+    }
+
+    #[test]
+    fn rejects_other_lengths_and_keys() {
+        let data = bincode::serialize(&rent::Rent::default()).unwrap();
+
+        assert_eq!(
+            Rent::from_bytes(&data[..Rent::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        // the in-memory length is not the account length
+        assert_eq!(
+            Rent::from_bytes(&[0; 24]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let short = MockAccount::new(ID, Pubkey::default(), 1, &data[..Rent::LEN - 1]);
+        assert_eq!(
+            Rent::from_account_info(short.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let other = MockAccount::new(Pubkey::new_unique(), Pubkey::default(), 1, &data);
+        assert_eq!(
+            Rent::from_account_info(other.info()).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn get_reads_the_in_memory_layout() {
+        for native in rents() {
+            // 3 words: the burn percent is followed by 7 bytes of padding,
+            // which may hold anything
+            let mut data = Vec::new();
+            data.extend(native.lamports_per_byte_year.to_le_bytes());
+            data.extend(native.exemption_threshold.to_le_bytes());
+            data.extend([
+                native.burn_percent,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+            ]);
+            mock::set_sysvar(&ID, Some(&data));
+
+            check(&Rent::get().unwrap(), &native);
+        }
+
+        mock::set_sysvar(&ID, None);
+        assert_eq!(Rent::get().err(), Some(ProgramError::UnsupportedSysvar));
+    }
+
+    #[test]
+    fn minimum_balance_matches_solana_program() {
+        for native in rents() {
+            let data = bincode::serialize(&native).unwrap();
+            let rent = Rent::from_bytes(&data).unwrap();
+
+            for space in [0, 1, 16, 82, 165, 10 * 1024 * 1024] {
+                let minimum_balance = native.minimum_balance(space);
+
+                assert_eq!(rent.minimum_balance(space), minimum_balance);
+                assert!(rent.is_exempt(minimum_balance, space));
+                assert!(!rent.is_exempt(minimum_balance - 1, space));
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{
+    clock::Slot, hash::Hash, program_error::ProgramError, sysvar::slot_hashes::ID,
+};
+
+use super::{borrow_sysvar_data, SortedEntries};
+
+/// Zero-copy view of the SlotHashes sysvar.
+///
+/// The sysvar holds the most recent slot hashes, sorted by descending slot,
+/// as a bincode-serialized `Vec<(Slot, Hash)>`. Lookups binary-search the
+/// entries in place.
+#[derive(Clone, Copy, Debug)]
+pub struct SlotHashes<'a> {
+    /// Serialized entries.
+    entries: SortedEntries<'a, Hash>,
+}
+
+const _: () = assert!(core::mem::align_of::<Hash>() == 1);
+
+impl<'a> SlotHashes<'a> {
+    /// Borrow the data of the sysvar account, to be viewed with
+    /// [`SlotHashes::from_bytes`].
+    #[inline(always)]
+    pub fn borrow_data(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = [u8]> + '_, ProgramError> {
+        borrow_sysvar_data(account_info, &ID)
+    }
+
+    /// View the bytes of the sysvar.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        // `Hash` is 32 bytes with an alignment of 1
+        let entries = unsafe { SortedEntries::from_bytes(data)? };
+
+        Ok(Self { entries })
+    }
+
+    /// Number of entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entry at `index`, or `None` if there is no such entry.
+    #[inline(always)]
+    pub fn entry(&self, index: usize) -> Option<(Slot, &'a Hash)> {
+        self.entries.entry(index)
+    }
+
+    /// Index of the entry of `slot`, or `None` if there is no such entry.
+    #[inline(always)]
+    pub fn position(&self, slot: Slot) -> Option<usize> {
+        self.entries.position(slot)
+    }
+
+    /// Hash of `slot`, or `None` if the slot is not in the sysvar.
+    #[inline(always)]
+    pub fn get(&self, slot: Slot) -> Option<&'a Hash> {
+        self.entries.get(slot)
+    }
+}
+
+/// Get the hash of `slot` with partial reads of the sysvar, without loading
+/// the whole sysvar.
+///
+/// Returns `None` if the slot is not in the sysvar, and `UnsupportedSysvar`
+/// off-chain or if the `sol_get_sysvar` syscall fails.
+#[cfg(feature = "get-sysvar")]
+pub fn get_slot_hash(slot: Slot) -> Result<Option<Hash>, ProgramError> {
+    use super::{find_entry_descending, get_sysvar_bytes};
+
+    let hash = find_entry_descending(slot, |data, offset| get_sysvar_bytes(&ID, data, offset))?;
+
+    Ok(hash.map(Hash::new_from_array))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+
+    fn serialize(entries: &[(Slot, Hash)]) -> Vec<u8> {
+        let mut data = (entries.len() as u64).to_le_bytes().to_vec();
+        for (slot, hash) in entries {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(hash.as_ref());
+        }
+        data
+    }
+
+    #[test]
+    fn finds_the_first_and_last_slots() {
+        let entries = [
+            (30, Hash::new_from_array([3; 32])),
+            (20, Hash::new_from_array([2; 32])),
+            (10, Hash::new_from_array([1; 32])),
+        ];
+        let data = serialize(&entries);
+        let slot_hashes = SlotHashes::from_bytes(&data).unwrap();
+
+        assert_eq!(slot_hashes.len(), 3);
+        assert_eq!(slot_hashes.get(30), Some(&entries[0].1));
+        assert_eq!(slot_hashes.get(10), Some(&entries[2].1));
+        assert_eq!(slot_hashes.entry(1), Some((20, &entries[1].1)));
+        assert_eq!(slot_hashes.entry(3), None);
+    }
+
+    #[test]
+    fn misses_absent_slots() {
+        let data = serialize(&[(30, Hash::default()), (10, Hash::default())]);
+        let slot_hashes = SlotHashes::from_bytes(&data).unwrap();
+
+        assert_eq!(slot_hashes.get(20), None);
+        assert_eq!(slot_hashes.get(40), None);
+        assert_eq!(slot_hashes.get(0), None);
+    }
+
+    #[test]
+    fn reads_an_empty_sysvar() {
+        let data = serialize(&[]);
+        let slot_hashes = SlotHashes::from_bytes(&data).unwrap();
+
+        assert!(slot_hashes.is_empty());
+        assert_eq!(slot_hashes.get(0), None);
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let data = serialize(&[(30, Hash::default()), (10, Hash::default())]);
+
+        assert_eq!(
+            SlotHashes::from_bytes(&data[..data.len() - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            SlotHashes::from_bytes(&data[..7]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
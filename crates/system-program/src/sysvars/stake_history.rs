@@ -0,0 +1,200 @@
+use core::ops::Deref;
+
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{clock::Epoch, program_error::ProgramError, sysvar::stake_history::ID};
+
+use super::{borrow_sysvar_data, SortedEntries};
+
+/// Stake activation and deactivation totals of an epoch.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakeHistoryEntry {
+    /// Effective stake at this epoch.
+    effective: [u8; 8],
+
+    /// Sum of portion of activations this epoch.
+    activating: [u8; 8],
+
+    /// Sum of portion of deactivations this epoch.
+    deactivating: [u8; 8],
+}
+
+const _: () = assert!(core::mem::size_of::<StakeHistoryEntry>() == StakeHistoryEntry::LEN);
+const _: () = assert!(core::mem::align_of::<StakeHistoryEntry>() == 1);
+
+impl StakeHistoryEntry {
+    /// Length of the entry data.
+    pub const LEN: usize = 24;
+
+    #[inline(always)]
+    pub fn effective(&self) -> u64 {
+        u64::from_le_bytes(self.effective)
+    }
+
+    #[inline(always)]
+    pub fn activating(&self) -> u64 {
+        u64::from_le_bytes(self.activating)
+    }
+
+    #[inline(always)]
+    pub fn deactivating(&self) -> u64 {
+        u64::from_le_bytes(self.deactivating)
+    }
+}
+
+/// Zero-copy view of the StakeHistory sysvar.
+///
+/// The sysvar holds the stake history of the most recent epochs, sorted by
+/// descending epoch, as a bincode-serialized
+/// `Vec<(Epoch, StakeHistoryEntry)>`. Lookups binary-search the entries in
+/// place.
+#[derive(Clone, Copy, Debug)]
+pub struct StakeHistory<'a> {
+    /// Serialized entries.
+    entries: SortedEntries<'a, StakeHistoryEntry>,
+}
+
+impl<'a> StakeHistory<'a> {
+    /// Borrow the data of the sysvar account, to be viewed with
+    /// [`StakeHistory::from_bytes`].
+    #[inline(always)]
+    pub fn borrow_data(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = [u8]> + '_, ProgramError> {
+        borrow_sysvar_data(account_info, &ID)
+    }
+
+    /// View the bytes of the sysvar.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        // `StakeHistoryEntry` has an alignment of 1 and only byte arrays
+        let entries = unsafe { SortedEntries::from_bytes(data)? };
+
+        Ok(Self { entries })
+    }
+
+    /// Number of entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entry at `index`, or `None` if there is no such entry.
+    #[inline(always)]
+    pub fn entry(&self, index: usize) -> Option<(Epoch, &'a StakeHistoryEntry)> {
+        self.entries.entry(index)
+    }
+
+    /// Index of the entry of `epoch`, or `None` if there is no such entry.
+    #[inline(always)]
+    pub fn position(&self, epoch: Epoch) -> Option<usize> {
+        self.entries.position(epoch)
+    }
+
+    /// Stake history of `epoch`, or `None` if the epoch is not in the sysvar.
+    #[inline(always)]
+    pub fn get(&self, epoch: Epoch) -> Option<&'a StakeHistoryEntry> {
+        self.entries.get(epoch)
+    }
+}
+
+/// Get the stake history of `epoch` with partial reads of the sysvar,
+/// without loading the whole sysvar.
+///
+/// Returns `None` if the epoch is not in the sysvar, and `UnsupportedSysvar`
+/// off-chain or if the `sol_get_sysvar` syscall fails.
+#[cfg(feature = "get-sysvar")]
+pub fn get_stake_history_entry(epoch: Epoch) -> Result<Option<StakeHistoryEntry>, ProgramError> {
+    use super::{find_entry_descending, get_sysvar_bytes};
+
+    let entry = find_entry_descending(epoch, |data, offset| get_sysvar_bytes(&ID, data, offset))?;
+
+    Ok(entry.map(|entry| unsafe {
+        core::mem::transmute::<[u8; StakeHistoryEntry::LEN], StakeHistoryEntry>(entry)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::stake_history;
+
+    use super::*;
+
+    fn stake_history(epochs: &[Epoch]) -> stake_history::StakeHistory {
+        let mut stake_history = stake_history::StakeHistory::default();
+        for epoch in epochs {
+            stake_history.add(
+                *epoch,
+                stake_history::StakeHistoryEntry {
+                    effective: epoch * 1_000,
+                    activating: epoch * 100,
+                    deactivating: epoch * 10,
+                },
+            );
+        }
+        stake_history
+    }
+
+    fn check(entry: Option<&StakeHistoryEntry>, native: Option<&stake_history::StakeHistoryEntry>) {
+        assert_eq!(
+            entry.map(|entry| (entry.effective(), entry.activating(), entry.deactivating())),
+            native.map(|native| (native.effective, native.activating, native.deactivating))
+        );
+    }
+
+    #[test]
+    fn finds_present_and_missing_epochs() {
+        let native = stake_history(&[3, 10, 11, 12, 40, 41]);
+        let data = bincode::serialize(&native).unwrap();
+        let stake_history = StakeHistory::from_bytes(&data).unwrap();
+
+        assert_eq!(stake_history.len(), 6);
+        for (index, (epoch, entry)) in native.iter().enumerate() {
+            assert_eq!(stake_history.position(*epoch), Some(index));
+            assert_eq!(
+                stake_history.entry(index).map(|(epoch, _)| epoch),
+                Some(*epoch)
+            );
+            check(
+                stake_history.entry(index).map(|(_, entry)| entry),
+                Some(entry),
+            );
+        }
+
+        for epoch in 0..50 {
+            check(stake_history.get(epoch), native.get(epoch));
+        }
+        assert_eq!(stake_history.entry(6), None);
+    }
+
+    #[test]
+    fn reads_an_empty_sysvar() {
+        let data = bincode::serialize(&stake_history(&[])).unwrap();
+        let stake_history = StakeHistory::from_bytes(&data).unwrap();
+
+        assert!(stake_history.is_empty());
+        assert!(stake_history.get(0).is_none());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let data = bincode::serialize(&stake_history(&[1, 2, 3])).unwrap();
+
+        assert_eq!(
+            StakeHistory::from_bytes(&data[..data.len() - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            StakeHistory::from_bytes(&data[..8 + 8 + StakeHistoryEntry::LEN]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            StakeHistory::from_bytes(&data[..7]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use solana_program::pubkey::Pubkey;
+
+use super::{optional_pubkey, Extension, ExtensionType};
+
+/// Interest-bearing extension data for mints.
+///
+/// Tokens accrue interest at an annual rate expressed in basis points,
+/// continuously compounded.
+#[repr(C)]
+pub struct InterestBearingConfig {
+    /// Authority that can set the interest rate and authority.
+    rate_authority: Pubkey,
+
+    /// Timestamp of initialization, from which to base interest calculations.
+    initialization_timestamp: [u8; 8],
+
+    /// Average rate from initialization until the last time it was updated.
+    pre_update_average_rate: [u8; 2],
+
+    /// Timestamp of the last update, used to calculate the total amount
+    /// accrued.
+    last_update_timestamp: [u8; 8],
+
+    /// Current rate, since the last update.
+    current_rate: [u8; 2],
+}
+
+const _: () = assert!(core::mem::size_of::<InterestBearingConfig>() == 52);
+const _: () = assert!(core::mem::align_of::<InterestBearingConfig>() == 1);
+
+unsafe impl Extension for InterestBearingConfig {
+    const TYPE: ExtensionType = ExtensionType::InterestBearingConfig;
+}
+
+impl InterestBearingConfig {
+    #[inline(always)]
+    pub fn rate_authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.rate_authority)
+    }
+
+    #[inline(always)]
+    pub fn initialization_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.initialization_timestamp)
+    }
+
+    #[inline(always)]
+    pub fn pre_update_average_rate(&self) -> i16 {
+        i16::from_le_bytes(self.pre_update_average_rate)
+    }
+
+    #[inline(always)]
+    pub fn last_update_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.last_update_timestamp)
+    }
+
+    #[inline(always)]
+    pub fn current_rate(&self) -> i16 {
+        i16::from_le_bytes(self.current_rate)
+    }
+}
@@ -0,0 +1,32 @@
+use solana_program::pubkey::Pubkey;
+
+use super::{optional_pubkey, Extension, ExtensionType};
+
+/// Metadata pointer extension data for mints.
+#[repr(C)]
+pub struct MetadataPointer {
+    /// Authority that can set the metadata address.
+    authority: Pubkey,
+
+    /// Account address that holds the metadata.
+    metadata_address: Pubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<MetadataPointer>() == 64);
+const _: () = assert!(core::mem::align_of::<MetadataPointer>() == 1);
+
+unsafe impl Extension for MetadataPointer {
+    const TYPE: ExtensionType = ExtensionType::MetadataPointer;
+}
+
+impl MetadataPointer {
+    #[inline(always)]
+    pub fn authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.authority)
+    }
+
+    #[inline(always)]
+    pub fn metadata_address(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.metadata_address)
+    }
+}
@@ -0,0 +1,24 @@
+use solana_program::pubkey::Pubkey;
+
+use super::{optional_pubkey, Extension, ExtensionType};
+
+/// Close authority extension data for mints.
+#[repr(C)]
+pub struct MintCloseAuthority {
+    /// Optional authority to close the mint.
+    close_authority: Pubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<MintCloseAuthority>() == 32);
+const _: () = assert!(core::mem::align_of::<MintCloseAuthority>() == 1);
+
+unsafe impl Extension for MintCloseAuthority {
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+}
+
+impl MintCloseAuthority {
+    #[inline(always)]
+    pub fn close_authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.close_authority)
+    }
+}
@@ -0,0 +1,649 @@
+mod interest_bearing_mint;
+mod metadata_pointer;
+mod mint_close_authority;
+mod permanent_delegate;
+mod token_metadata;
+mod transfer_fee;
+mod transfer_hook;
+
+pub use interest_bearing_mint::*;
+pub use metadata_pointer::*;
+pub use mint_close_authority::*;
+pub use permanent_delegate::*;
+pub use token_metadata::*;
+pub use transfer_fee::*;
+pub use transfer_hook::*;
+
+use nostd_token_program::state::{Account, Mint};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+/// Length of a multisignature account, which can never hold extensions.
+const MULTISIG_LEN: usize = 355;
+
+/// Offset of the account type in an account with extensions.
+///
+/// Mints are padded with zeroes up to the length of a token account so the
+/// account type is always at the same offset.
+const ACCOUNT_TYPE_OFFSET: usize = Account::LEN;
+
+/// Offset of the first TLV entry in an account with extensions.
+const TLV_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+
+/// Type of the account holding the extensions.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountType {
+    /// Marker for 0 data.
+    Uninitialized,
+
+    /// Mint account with additional extensions.
+    Mint,
+
+    /// Token holding account with additional extensions.
+    Account,
+}
+
+impl AccountType {
+    /// Length of the base state of the account type.
+    #[inline(always)]
+    fn base_len(self) -> usize {
+        match self {
+            AccountType::Mint => Mint::LEN,
+            _ => Account::LEN,
+        }
+    }
+}
+
+/// Extensions that can be applied to mints or accounts.
+///
+/// Mint extensions must only be applied to mint accounts, and account
+/// extensions must only be applied to token holding accounts.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtensionType {
+    /// Used as padding if the account size would otherwise be 355, same as a
+    /// multisig.
+    Uninitialized,
+
+    /// Includes transfer fee rate info and accompanying authorities to
+    /// withdraw and set the fee.
+    TransferFeeConfig,
+
+    /// Includes withheld transfer fees.
+    TransferFeeAmount,
+
+    /// Includes an optional mint close authority.
+    MintCloseAuthority,
+
+    /// Auditor configuration for confidential transfers.
+    ConfidentialTransferMint,
+
+    /// State for confidential transfers.
+    ConfidentialTransferAccount,
+
+    /// Specifies the default account state for new accounts.
+    DefaultAccountState,
+
+    /// Indicates that the account owner authority cannot be changed.
+    ImmutableOwner,
+
+    /// Requires inbound transfers to have a memo.
+    MemoTransfer,
+
+    /// Indicates that the tokens from this mint can't be transferred.
+    NonTransferable,
+
+    /// Tokens accrue interest over time.
+    InterestBearingConfig,
+
+    /// Locks privileged token operations from happening via CPI.
+    CpiGuard,
+
+    /// Includes an optional permanent delegate.
+    PermanentDelegate,
+
+    /// Indicates that the tokens in this account belong to a non-transferable
+    /// mint.
+    NonTransferableAccount,
+
+    /// Mint requires a CPI to a program implementing the "transfer hook"
+    /// interface.
+    TransferHook,
+
+    /// Indicates that the tokens in this account belong to a mint with a
+    /// transfer hook.
+    TransferHookAccount,
+
+    /// Includes encrypted withheld fees and the encryption public key that
+    /// they are encrypted under.
+    ConfidentialTransferFeeConfig,
+
+    /// Includes confidential withheld transfer fees.
+    ConfidentialTransferFeeAmount,
+
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds metadata.
+    MetadataPointer,
+
+    /// Mint contains token metadata.
+    TokenMetadata,
+
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group configurations.
+    GroupPointer,
+
+    /// Mint contains token group configurations.
+    TokenGroup,
+
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group member configurations.
+    GroupMemberPointer,
+
+    /// Mint contains token group member configurations.
+    TokenGroupMember,
+}
+
+impl ExtensionType {
+    /// Number of known extension types.
+    pub const COUNT: usize = 24;
+
+    /// Type of the account the extension applies to.
+    pub fn account_type(self) -> AccountType {
+        match self {
+            ExtensionType::Uninitialized => AccountType::Uninitialized,
+            ExtensionType::TransferFeeConfig
+            | ExtensionType::MintCloseAuthority
+            | ExtensionType::ConfidentialTransferMint
+            | ExtensionType::DefaultAccountState
+            | ExtensionType::NonTransferable
+            | ExtensionType::InterestBearingConfig
+            | ExtensionType::PermanentDelegate
+            | ExtensionType::TransferHook
+            | ExtensionType::ConfidentialTransferFeeConfig
+            | ExtensionType::MetadataPointer
+            | ExtensionType::TokenMetadata
+            | ExtensionType::GroupPointer
+            | ExtensionType::TokenGroup
+            | ExtensionType::GroupMemberPointer
+            | ExtensionType::TokenGroupMember => AccountType::Mint,
+            ExtensionType::TransferFeeAmount
+            | ExtensionType::ConfidentialTransferAccount
+            | ExtensionType::ImmutableOwner
+            | ExtensionType::MemoTransfer
+            | ExtensionType::CpiGuard
+            | ExtensionType::NonTransferableAccount
+            | ExtensionType::TransferHookAccount
+            | ExtensionType::ConfidentialTransferFeeAmount => AccountType::Account,
+        }
+    }
+}
+
+impl TryFrom<u16> for ExtensionType {
+    type Error = ProgramError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        if (value as usize) < Self::COUNT {
+            // SAFETY: the discriminants are contiguous from 0 to `COUNT - 1`.
+            Ok(unsafe { core::mem::transmute::<u16, ExtensionType>(value) })
+        } else {
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
+
+/// Fixed-length extension data that can be viewed in place.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` with an alignment of 1, and every bit
+/// pattern must be a valid value.
+pub unsafe trait Extension: Sized {
+    /// Extension type of the TLV entry holding the data.
+    const TYPE: ExtensionType;
+}
+
+/// A TLV entry of the extension area.
+#[derive(Clone, Copy, Debug)]
+pub struct TlvEntry<'a> {
+    extension_type: u16,
+    value: &'a [u8],
+}
+
+impl<'a> TlvEntry<'a> {
+    /// Raw extension type, which may not be known to this crate.
+    #[inline(always)]
+    pub fn raw_extension_type(&self) -> u16 {
+        self.extension_type
+    }
+
+    /// Extension type, or `None` if the type is not known to this crate.
+    #[inline(always)]
+    pub fn extension_type(&self) -> Option<ExtensionType> {
+        ExtensionType::try_from(self.extension_type).ok()
+    }
+
+    /// Value of the entry.
+    #[inline(always)]
+    pub fn value(&self) -> &'a [u8] {
+        self.value
+    }
+}
+
+/// Iterator over the TLV entries of the extension area.
+///
+/// Iteration stops at the end of the data or at the first uninitialized
+/// entry. A malformed entry yields an error and ends the iteration.
+pub struct Extensions<'a> {
+    tlv_data: &'a [u8],
+}
+
+impl<'a> Iterator for Extensions<'a> {
+    type Item = Result<TlvEntry<'a>, ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // - [0..2]: extension type
+        // - [2..4]: value length
+        // - [4.. ]: value
+        if self.tlv_data.len() < 2 {
+            return None;
+        }
+
+        let extension_type = u16::from_le_bytes([self.tlv_data[0], self.tlv_data[1]]);
+
+        if extension_type == ExtensionType::Uninitialized as u16 {
+            return None;
+        }
+
+        let entry = self.tlv_data.get(2..4).and_then(|length| {
+            let length = u16::from_le_bytes([length[0], length[1]]) as usize;
+            self.tlv_data
+                .get(4..4 + length)
+                .map(|value| (value, 4 + length))
+        });
+
+        match entry {
+            Some((value, entry_len)) => {
+                self.tlv_data = &self.tlv_data[entry_len..];
+                Some(Ok(TlvEntry {
+                    extension_type,
+                    value,
+                }))
+            }
+            None => {
+                self.tlv_data = &[];
+                Some(Err(ProgramError::InvalidAccountData))
+            }
+        }
+    }
+}
+
+/// Iterate over the extensions of a mint or token account.
+///
+/// `data` is the whole account data. Accounts without extensions yield no
+/// entries.
+pub fn extensions(data: &[u8], account_type: AccountType) -> Result<Extensions<'_>, ProgramError> {
+    let base_len = account_type.base_len();
+
+    if data.len() == base_len {
+        return Ok(Extensions { tlv_data: &[] });
+    }
+
+    if account_type == AccountType::Uninitialized
+        || data.len() == MULTISIG_LEN
+        || data.len() <= ACCOUNT_TYPE_OFFSET
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if data[base_len..ACCOUNT_TYPE_OFFSET]
+        .iter()
+        .any(|byte| *byte != 0)
+        || data[ACCOUNT_TYPE_OFFSET] != account_type as u8
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(Extensions {
+        tlv_data: &data[TLV_OFFSET..],
+    })
+}
+
+/// Find the value of an extension of a mint or token account.
+///
+/// Returns `None` if the account does not have the extension.
+pub fn get_extension_bytes(
+    data: &[u8],
+    extension_type: ExtensionType,
+) -> Result<Option<&[u8]>, ProgramError> {
+    for entry in extensions(data, extension_type.account_type())? {
+        let entry = entry?;
+
+        if entry.extension_type == extension_type as u16 {
+            return Ok(Some(entry.value));
+        }
+    }
+
+    Ok(None)
+}
+
+/// View the data of an extension of a mint or token account.
+///
+/// Returns `None` if the account does not have the extension.
+pub fn get_extension<T: Extension>(data: &[u8]) -> Result<Option<&T>, ProgramError> {
+    match get_extension_bytes(data, T::TYPE)? {
+        Some(value) if value.len() == core::mem::size_of::<T>() => {
+            Ok(Some(unsafe { &*(value.as_ptr() as *const T) }))
+        }
+        Some(_) => Err(ProgramError::InvalidAccountData),
+        None => Ok(None),
+    }
+}
+
+/// Read an `OptionalNonZeroPubkey`, where the zero pubkey means `None`.
+#[inline(always)]
+fn optional_pubkey(value: &Pubkey) -> Option<&Pubkey> {
+    (*value != Pubkey::default()).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use spl_token_2022::{
+        extension::{
+            interest_bearing_mint, metadata_pointer, mint_close_authority, permanent_delegate,
+            transfer_fee, transfer_hook, BaseStateWithExtensions, BaseStateWithExtensionsMut,
+            ExtensionType as SplExtensionType, StateWithExtensionsMut,
+        },
+        state,
+    };
+    use std::{vec, vec::Vec};
+
+    use super::*;
+
+    const EXTENSIONS: [SplExtensionType; 3] = [
+        SplExtensionType::TransferFeeConfig,
+        SplExtensionType::MintCloseAuthority,
+        SplExtensionType::MetadataPointer,
+    ];
+
+    fn spl_transfer_fee(
+        epoch: u64,
+        maximum_fee: u64,
+        basis_points: u16,
+    ) -> transfer_fee::TransferFee {
+        transfer_fee::TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        }
+    }
+
+    /// Initialize a mint with the extensions of `EXTENSIONS`.
+    fn mint_with_extensions() -> Vec<u8> {
+        let len = SplExtensionType::try_calculate_account_len::<state::Mint>(&EXTENSIONS).unwrap();
+        let mut data = vec![0; len];
+        let mut mint =
+            StateWithExtensionsMut::<state::Mint>::unpack_uninitialized(&mut data).unwrap();
+
+        let config = mint
+            .init_extension::<transfer_fee::TransferFeeConfig>(true)
+            .unwrap();
+        config.withdraw_withheld_authority = Some(Pubkey::new_unique()).try_into().unwrap();
+        config.withheld_amount = 7.into();
+        config.older_transfer_fee = spl_transfer_fee(0, 10, 25);
+        config.newer_transfer_fee = spl_transfer_fee(100, 1_000, 50);
+
+        mint.init_extension::<mint_close_authority::MintCloseAuthority>(true)
+            .unwrap()
+            .close_authority = Some(Pubkey::new_unique()).try_into().unwrap();
+
+        mint.init_extension::<metadata_pointer::MetadataPointer>(true)
+            .unwrap()
+            .metadata_address = Some(Pubkey::new_unique()).try_into().unwrap();
+
+        mint.base = state::Mint {
+            supply: 42,
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+
+        data
+    }
+
+    #[test]
+    fn iterates_spl_token_2022_extensions() {
+        let data = mint_with_extensions();
+        let mut spl_data = data.clone();
+        let spl_mint = StateWithExtensionsMut::<state::Mint>::unpack(&mut spl_data).unwrap();
+
+        let entries = extensions(&data, AccountType::Mint)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let types = entries
+            .iter()
+            .map(|entry| entry.raw_extension_type())
+            .collect::<Vec<_>>();
+        let spl_types = spl_mint
+            .get_extension_types()
+            .unwrap()
+            .into_iter()
+            .map(|extension_type| extension_type as u16)
+            .collect::<Vec<_>>();
+
+        assert_eq!(types, spl_types);
+        assert_eq!(
+            entries[0].extension_type(),
+            Some(ExtensionType::TransferFeeConfig)
+        );
+
+        let config = get_extension::<TransferFeeConfig>(&data).unwrap().unwrap();
+        let spl_config = spl_mint
+            .get_extension::<transfer_fee::TransferFeeConfig>()
+            .unwrap();
+        assert_eq!(
+            config.withdraw_withheld_authority(),
+            Option::<Pubkey>::from(spl_config.withdraw_withheld_authority).as_ref()
+        );
+        assert_eq!(config.transfer_fee_config_authority(), None);
+        assert_eq!(
+            config.withheld_amount(),
+            u64::from(spl_config.withheld_amount)
+        );
+        assert_eq!(config.newer_transfer_fee().epoch(), 100);
+
+        let close_authority = get_extension::<MintCloseAuthority>(&data).unwrap().unwrap();
+        let spl_close_authority = spl_mint
+            .get_extension::<mint_close_authority::MintCloseAuthority>()
+            .unwrap();
+        assert_eq!(
+            close_authority.close_authority(),
+            Option::<Pubkey>::from(spl_close_authority.close_authority).as_ref()
+        );
+
+        let pointer = get_extension::<MetadataPointer>(&data).unwrap().unwrap();
+        let spl_pointer = spl_mint
+            .get_extension::<metadata_pointer::MetadataPointer>()
+            .unwrap();
+        assert_eq!(
+            pointer.metadata_address(),
+            Option::<Pubkey>::from(spl_pointer.metadata_address).as_ref()
+        );
+        assert_eq!(pointer.authority(), None);
+
+        assert!(get_extension::<TransferHook>(&data).unwrap().is_none());
+    }
+
+    #[test]
+    fn pod_extensions_match_spl_token_2022() {
+        let len = SplExtensionType::try_calculate_account_len::<state::Mint>(&[
+            SplExtensionType::TransferHook,
+            SplExtensionType::PermanentDelegate,
+            SplExtensionType::InterestBearingConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0; len];
+        let mut mint =
+            StateWithExtensionsMut::<state::Mint>::unpack_uninitialized(&mut data).unwrap();
+
+        mint.init_extension::<transfer_hook::TransferHook>(true)
+            .unwrap()
+            .program_id = Some(Pubkey::new_unique()).try_into().unwrap();
+
+        mint.init_extension::<permanent_delegate::PermanentDelegate>(true)
+            .unwrap()
+            .delegate = Some(Pubkey::new_unique()).try_into().unwrap();
+
+        let config = mint
+            .init_extension::<interest_bearing_mint::InterestBearingConfig>(true)
+            .unwrap();
+        config.rate_authority = Some(Pubkey::new_unique()).try_into().unwrap();
+        config.initialization_timestamp = 1_700_000_000.into();
+        config.pre_update_average_rate = (-250).into();
+        config.last_update_timestamp = 1_700_086_400.into();
+        config.current_rate = i16::MIN.into();
+
+        mint.base = state::Mint {
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+
+        let mut spl_data = data.clone();
+        let spl_mint = StateWithExtensionsMut::<state::Mint>::unpack(&mut spl_data).unwrap();
+
+        let hook = get_extension::<TransferHook>(&data).unwrap().unwrap();
+        let spl_hook = spl_mint
+            .get_extension::<transfer_hook::TransferHook>()
+            .unwrap();
+        assert_eq!(
+            hook.authority(),
+            Option::<Pubkey>::from(spl_hook.authority).as_ref()
+        );
+        assert_eq!(hook.authority(), None);
+        assert_eq!(
+            hook.program_id(),
+            Option::<Pubkey>::from(spl_hook.program_id).as_ref()
+        );
+
+        let delegate = get_extension::<PermanentDelegate>(&data).unwrap().unwrap();
+        let spl_delegate = spl_mint
+            .get_extension::<permanent_delegate::PermanentDelegate>()
+            .unwrap();
+        assert_eq!(
+            delegate.delegate(),
+            Option::<Pubkey>::from(spl_delegate.delegate).as_ref()
+        );
+
+        let config = get_extension::<InterestBearingConfig>(&data)
+            .unwrap()
+            .unwrap();
+        let spl_config = spl_mint
+            .get_extension::<interest_bearing_mint::InterestBearingConfig>()
+            .unwrap();
+        assert_eq!(
+            config.rate_authority(),
+            Option::<Pubkey>::from(spl_config.rate_authority).as_ref()
+        );
+        assert_eq!(
+            config.initialization_timestamp(),
+            i64::from(spl_config.initialization_timestamp)
+        );
+        assert_eq!(
+            config.pre_update_average_rate(),
+            i16::from(spl_config.pre_update_average_rate)
+        );
+        assert_eq!(
+            config.last_update_timestamp(),
+            i64::from(spl_config.last_update_timestamp)
+        );
+        assert_eq!(config.current_rate(), i16::from(spl_config.current_rate));
+    }
+
+    #[test]
+    fn base_accounts_have_no_extensions() {
+        assert_eq!(
+            extensions(&[0; Mint::LEN], AccountType::Mint)
+                .unwrap()
+                .count(),
+            0
+        );
+        assert_eq!(
+            extensions(&[0; Account::LEN], AccountType::Account)
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_extension_areas() {
+        let data = mint_with_extensions();
+
+        // account type of a token account
+        let mut wrong_type = data.clone();
+        wrong_type[ACCOUNT_TYPE_OFFSET] = AccountType::Account as u8;
+        assert!(extensions(&wrong_type, AccountType::Mint).is_err());
+
+        // non-zero padding between the mint and the account type
+        let mut dirty_padding = data.clone();
+        dirty_padding[Mint::LEN] = 1;
+        assert!(extensions(&dirty_padding, AccountType::Mint).is_err());
+
+        // multisig length
+        assert!(extensions(&[0; MULTISIG_LEN], AccountType::Account).is_err());
+
+        // truncated value: an error ends the iteration
+        let truncated = &data[..data.len() - 1];
+        let mut entries = extensions(truncated, AccountType::Mint).unwrap();
+        assert!(entries.next().unwrap().is_ok());
+        assert!(entries.next().unwrap().is_ok());
+        assert_eq!(
+            entries.next().unwrap().unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+        assert!(entries.next().is_none());
+
+        // an uninitialized entry ends the iteration
+        let mut padded = data.clone();
+        padded.extend_from_slice(&[0; 4]);
+        assert_eq!(extensions(&padded, AccountType::Mint).unwrap().count(), 3);
+    }
+
+    #[test]
+    fn calculate_fee_matches_spl_token_2022() {
+        let amounts = [
+            0,
+            1,
+            9_999,
+            10_000,
+            10_001,
+            123_456_789,
+            u64::MAX / 2,
+            u64::MAX,
+        ];
+
+        for basis_points in [0, 1, 25, 9_999, MAX_FEE_BASIS_POINTS] {
+            for maximum_fee in [0, 1, 5_000, u64::MAX] {
+                let spl_fee = spl_transfer_fee(0, maximum_fee, basis_points);
+                let mut bytes = [0; 18];
+                bytes[8..16].copy_from_slice(&maximum_fee.to_le_bytes());
+                bytes[16..].copy_from_slice(&basis_points.to_le_bytes());
+                let fee = unsafe { &*(bytes.as_ptr() as *const TransferFee) };
+
+                for amount in amounts {
+                    assert_eq!(fee.calculate_fee(amount), spl_fee.calculate_fee(amount));
+                    assert_eq!(
+                        fee.calculate_post_fee_amount(amount),
+                        spl_fee.calculate_post_fee_amount(amount)
+                    );
+                }
+            }
+        }
+    }
+}
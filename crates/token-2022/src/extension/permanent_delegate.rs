@@ -0,0 +1,24 @@
+use solana_program::pubkey::Pubkey;
+
+use super::{optional_pubkey, Extension, ExtensionType};
+
+/// Permanent delegate extension data for mints.
+#[repr(C)]
+pub struct PermanentDelegate {
+    /// Optional permanent delegate for transferring or burning tokens.
+    delegate: Pubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<PermanentDelegate>() == 32);
+const _: () = assert!(core::mem::align_of::<PermanentDelegate>() == 1);
+
+unsafe impl Extension for PermanentDelegate {
+    const TYPE: ExtensionType = ExtensionType::PermanentDelegate;
+}
+
+impl PermanentDelegate {
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.delegate)
+    }
+}
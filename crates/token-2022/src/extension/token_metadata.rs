@@ -0,0 +1,274 @@
+use nostd_entrypoint_invoke::reader::Reader;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use super::{get_extension_bytes, optional_pubkey, ExtensionType};
+
+/// Token metadata extension data for mints.
+///
+/// The data is variable-length and Borsh-serialized; this view borrows the
+/// strings from the account data.
+#[derive(Clone, Copy, Debug)]
+pub struct TokenMetadata<'a> {
+    /// Authority that can update the metadata.
+    update_authority: &'a Pubkey,
+
+    /// Mint the metadata belongs to.
+    mint: &'a Pubkey,
+
+    /// Longer name of the token.
+    name: &'a str,
+
+    /// Shortened symbol of the token.
+    symbol: &'a str,
+
+    /// URI pointing to richer metadata.
+    uri: &'a str,
+
+    /// Additional key-value pairs.
+    additional_metadata: AdditionalMetadata<'a>,
+}
+
+impl<'a> TokenMetadata<'a> {
+    /// View the token metadata of a mint, or `None` if the mint does not
+    /// have the extension.
+    ///
+    /// `data` is the whole mint account data.
+    pub fn from_account_data(data: &'a [u8]) -> Result<Option<Self>, ProgramError> {
+        get_extension_bytes(data, ExtensionType::TokenMetadata)?
+            .map(Self::from_bytes)
+            .transpose()
+    }
+
+    /// View the bytes of a token metadata extension value.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        Self::read(&mut Reader::new(data)).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    fn read(reader: &mut Reader<'a>) -> Option<Self> {
+        let update_authority = reader.read_pubkey()?;
+        let mint = reader.read_pubkey()?;
+        let name = reader.read_borsh_str()?;
+        let symbol = reader.read_borsh_str()?;
+        let uri = reader.read_borsh_str()?;
+
+        // validate the additional metadata up front so iterating over it
+        // cannot fail
+        let remaining = reader.read_u32()?;
+        let additional_metadata = AdditionalMetadata {
+            reader: *reader,
+            remaining,
+        };
+
+        for _ in 0..remaining {
+            reader.read_borsh_str()?;
+            reader.read_borsh_str()?;
+        }
+
+        Some(Self {
+            update_authority,
+            mint,
+            name,
+            symbol,
+            uri,
+            additional_metadata,
+        })
+    }
+
+    #[inline(always)]
+    pub fn update_authority(&self) -> Option<&'a Pubkey> {
+        optional_pubkey(self.update_authority)
+    }
+
+    #[inline(always)]
+    pub fn mint(&self) -> &'a Pubkey {
+        self.mint
+    }
+
+    #[inline(always)]
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    #[inline(always)]
+    pub fn symbol(&self) -> &'a str {
+        self.symbol
+    }
+
+    #[inline(always)]
+    pub fn uri(&self) -> &'a str {
+        self.uri
+    }
+
+    /// Iterate over the additional key-value pairs.
+    #[inline(always)]
+    pub fn additional_metadata(&self) -> AdditionalMetadata<'a> {
+        self.additional_metadata
+    }
+
+    /// Find the value of an additional metadata key.
+    pub fn get_additional_metadata(&self, key: &str) -> Option<&'a str> {
+        self.additional_metadata()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Iterator over the additional key-value pairs of a token metadata.
+#[derive(Clone, Copy, Debug)]
+pub struct AdditionalMetadata<'a> {
+    reader: Reader<'a>,
+    remaining: u32,
+}
+
+impl<'a> Iterator for AdditionalMetadata<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        Some((self.reader.read_borsh_str()?, self.reader.read_borsh_str()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use spl_token_2022::{
+        extension::{BaseStateWithExtensionsMut, StateWithExtensionsMut},
+        state,
+    };
+    use spl_token_metadata_interface::{borsh, state::TokenMetadata as SplTokenMetadata};
+    use std::{vec, vec::Vec};
+
+    use super::{
+        super::{Mint, TLV_OFFSET},
+        *,
+    };
+
+    fn spl_token_metadata() -> SplTokenMetadata {
+        SplTokenMetadata {
+            update_authority: Some(Pubkey::new_unique()).try_into().unwrap(),
+            mint: Pubkey::new_unique(),
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            uri: "https://example.com/token.json".into(),
+            additional_metadata: vec![
+                ("color".into(), "blue".into()),
+                ("empty".into(), "".into()),
+                ("ünïcödé".into(), "✓".into()),
+            ],
+        }
+    }
+
+    fn check(metadata: &TokenMetadata, spl: &SplTokenMetadata) {
+        assert_eq!(
+            metadata.update_authority(),
+            Option::<Pubkey>::from(spl.update_authority).as_ref()
+        );
+        assert_eq!(metadata.mint(), &spl.mint);
+        assert_eq!(metadata.name(), spl.name);
+        assert_eq!(metadata.symbol(), spl.symbol);
+        assert_eq!(metadata.uri(), spl.uri);
+        assert!(metadata.additional_metadata().eq(spl
+            .additional_metadata
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))));
+    }
+
+    /// Initialize a mint with the token metadata extension.
+    fn mint_with_token_metadata(spl: &SplTokenMetadata) -> Vec<u8> {
+        let value_len = borsh::to_vec(spl).unwrap().len();
+        let mut data = vec![0; TLV_OFFSET + 4 + value_len];
+        let mut mint =
+            StateWithExtensionsMut::<state::Mint>::unpack_uninitialized(&mut data).unwrap();
+
+        mint.init_variable_len_extension(spl, false).unwrap();
+        mint.base = state::Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+
+        data
+    }
+
+    #[test]
+    fn reads_borsh_token_metadata() {
+        for spl in [spl_token_metadata(), SplTokenMetadata::default()] {
+            let data = borsh::to_vec(&spl).unwrap();
+            check(&TokenMetadata::from_bytes(&data).unwrap(), &spl);
+        }
+
+        let data = borsh::to_vec(&spl_token_metadata()).unwrap();
+        let metadata = TokenMetadata::from_bytes(&data).unwrap();
+        assert_eq!(metadata.get_additional_metadata("color"), Some("blue"));
+        assert_eq!(metadata.get_additional_metadata("empty"), Some(""));
+        assert_eq!(metadata.get_additional_metadata("ünïcödé"), Some("✓"));
+        assert_eq!(metadata.get_additional_metadata("size"), None);
+
+        let data = borsh::to_vec(&SplTokenMetadata::default()).unwrap();
+        let metadata = TokenMetadata::from_bytes(&data).unwrap();
+        assert_eq!(metadata.update_authority(), None);
+        assert_eq!(metadata.additional_metadata().count(), 0);
+    }
+
+    #[test]
+    fn reads_the_token_metadata_of_mints() {
+        let spl = spl_token_metadata();
+        let data = mint_with_token_metadata(&spl);
+
+        check(
+            &TokenMetadata::from_account_data(&data).unwrap().unwrap(),
+            &spl,
+        );
+
+        // a mint without extensions
+        assert!(TokenMetadata::from_account_data(&data[..Mint::LEN])
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn rejects_truncated_and_invalid_strings() {
+        let spl = spl_token_metadata();
+        let data = borsh::to_vec(&spl).unwrap();
+
+        // every cut, including inside the strings and the additional metadata
+        for len in 0..data.len() {
+            assert_eq!(
+                TokenMetadata::from_bytes(&data[..len]).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+        }
+
+        // the name "Token" starts after the pubkeys and its length
+        let mut invalid_utf8 = data.clone();
+        invalid_utf8[64 + 4] = 0xff;
+        assert_eq!(
+            TokenMetadata::from_bytes(&invalid_utf8).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // more additional metadata entries than the data holds
+        let without_entries = SplTokenMetadata {
+            additional_metadata: vec![],
+            ..spl
+        };
+        let len_offset = borsh::to_vec(&without_entries).unwrap().len() - 4;
+        let mut extra_entry = data.clone();
+        assert_eq!(extra_entry[len_offset..len_offset + 4], 3u32.to_le_bytes());
+        extra_entry[len_offset..len_offset + 4].copy_from_slice(&4u32.to_le_bytes());
+        assert_eq!(
+            TokenMetadata::from_bytes(&extra_entry).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use solana_program::{clock::Epoch, pubkey::Pubkey};
+
+use super::{optional_pubkey, Extension, ExtensionType};
+
+/// Maximum value of the transfer fee basis points (100%).
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+/// Transfer fee information.
+#[repr(C)]
+pub struct TransferFee {
+    /// First epoch where the transfer fee takes effect.
+    epoch: [u8; 8],
+
+    /// Maximum fee assessed on transfers, expressed as an amount of tokens.
+    maximum_fee: [u8; 8],
+
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount, ie. increments of 0.01%.
+    transfer_fee_basis_points: [u8; 2],
+}
+
+const _: () = assert!(core::mem::size_of::<TransferFee>() == 18);
+const _: () = assert!(core::mem::align_of::<TransferFee>() == 1);
+
+impl TransferFee {
+    #[inline(always)]
+    pub fn epoch(&self) -> Epoch {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    #[inline(always)]
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    #[inline(always)]
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Calculate the fee assessed on a transfer of `pre_fee_amount` tokens.
+    ///
+    /// The fee is rounded up and capped at the maximum fee.
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        let transfer_fee_basis_points = self.transfer_fee_basis_points() as u128;
+
+        if transfer_fee_basis_points == 0 || pre_fee_amount == 0 {
+            return Some(0);
+        }
+
+        let numerator = (pre_fee_amount as u128).checked_mul(transfer_fee_basis_points)?;
+        let raw_fee = numerator
+            .checked_add(MAX_FEE_BASIS_POINTS as u128 - 1)?
+            .checked_div(MAX_FEE_BASIS_POINTS as u128)?;
+
+        Some(u64::try_from(raw_fee).ok()?.min(self.maximum_fee()))
+    }
+
+    /// Calculate the amount received by the destination of a transfer of
+    /// `pre_fee_amount` tokens.
+    #[inline(always)]
+    pub fn calculate_post_fee_amount(&self, pre_fee_amount: u64) -> Option<u64> {
+        pre_fee_amount.checked_sub(self.calculate_fee(pre_fee_amount)?)
+    }
+}
+
+/// Transfer fee extension data for mints.
+#[repr(C)]
+pub struct TransferFeeConfig {
+    /// Optional authority to set the fee.
+    transfer_fee_config_authority: Pubkey,
+
+    /// Withdraw from mint instructions must be signed by this key.
+    withdraw_withheld_authority: Pubkey,
+
+    /// Withheld transfer fee tokens that have been moved to the mint for
+    /// withdrawal.
+    withheld_amount: [u8; 8],
+
+    /// Older transfer fee, used if the current epoch is before the epoch of
+    /// the newer transfer fee.
+    older_transfer_fee: TransferFee,
+
+    /// Newer transfer fee, used from its epoch onwards.
+    newer_transfer_fee: TransferFee,
+}
+
+const _: () = assert!(core::mem::size_of::<TransferFeeConfig>() == 108);
+const _: () = assert!(core::mem::align_of::<TransferFeeConfig>() == 1);
+
+unsafe impl Extension for TransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+}
+
+impl TransferFeeConfig {
+    #[inline(always)]
+    pub fn transfer_fee_config_authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.transfer_fee_config_authority)
+    }
+
+    #[inline(always)]
+    pub fn withdraw_withheld_authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.withdraw_withheld_authority)
+    }
+
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    #[inline(always)]
+    pub fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    #[inline(always)]
+    pub fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    /// Get the transfer fee in effect at the given epoch.
+    #[inline(always)]
+    pub fn get_epoch_fee(&self, epoch: Epoch) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+
+    /// Calculate the fee assessed on a transfer of `pre_fee_amount` tokens at
+    /// the given epoch.
+    #[inline(always)]
+    pub fn calculate_epoch_fee(&self, epoch: Epoch, pre_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch).calculate_fee(pre_fee_amount)
+    }
+}
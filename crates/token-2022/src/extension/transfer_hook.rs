@@ -0,0 +1,32 @@
+use solana_program::pubkey::Pubkey;
+
+use super::{optional_pubkey, Extension, ExtensionType};
+
+/// Transfer hook extension data for mints.
+#[repr(C)]
+pub struct TransferHook {
+    /// Authority that can set the transfer hook program id.
+    authority: Pubkey,
+
+    /// Program that authorizes the transfer.
+    program_id: Pubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<TransferHook>() == 64);
+const _: () = assert!(core::mem::align_of::<TransferHook>() == 1);
+
+unsafe impl Extension for TransferHook {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+}
+
+impl TransferHook {
+    #[inline(always)]
+    pub fn authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.authority)
+    }
+
+    #[inline(always)]
+    pub fn program_id(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.program_id)
+    }
+}
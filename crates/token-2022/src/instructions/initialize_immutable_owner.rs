@@ -0,0 +1,60 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Initializes the immutable owner extension on a new token account.
+///
+/// Fails if the account has already been initialized, so must be called
+/// before `InitializeAccount`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Token account
+pub struct InitializeImmutableOwner<'a> {
+    /// Token account.
+    pub account: &'a NoStdAccountInfo,
+}
+
+impl<'a> InitializeImmutableOwner<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 1] = [self.account.to_meta_c()];
+
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: instruction_data.as_ptr(),
+            data_len: 1,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.account], signers)
+    }
+
+    /// Instruction data, as packed by
+    /// `TokenInstruction::InitializeImmutableOwner`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [22]
+    }
+}
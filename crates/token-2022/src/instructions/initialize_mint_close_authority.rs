@@ -0,0 +1,77 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Initializes the close account authority on a new mint.
+///
+/// Fails if the mint has already been initialized, so must be called before
+/// `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Mint account
+pub struct InitializeMintCloseAuthority<'a, 'b> {
+    /// Mint account.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Authority that must sign the `CloseAccount` instruction on the mint.
+    pub close_authority: Option<&'b Pubkey>,
+}
+
+impl<'a, 'b> InitializeMintCloseAuthority<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 1] = [self.mint.to_meta_c()];
+
+        let (instruction_data, data_len) = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: instruction_data.as_ptr(),
+            data_len: data_len as u64,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.mint], signers)
+    }
+
+    /// Instruction data, as packed by
+    /// `TokenInstruction::InitializeMintCloseAuthority`.
+    ///
+    /// Returns the data buffer together with the number of bytes in use.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> ([u8; 34], usize) {
+        // - [0    ]: instruction discriminator
+        // - [1    ]: close authority presence flag
+        // - [2..34]: close authority pubkey (optional)
+        let mut instruction_data = [0; 34];
+        instruction_data[0] = 25;
+
+        if let Some(close_authority) = self.close_authority {
+            instruction_data[1] = 1;
+            instruction_data[2..34].copy_from_slice(close_authority.as_ref());
+            (instruction_data, 34)
+        } else {
+            (instruction_data, 2)
+        }
+    }
+}
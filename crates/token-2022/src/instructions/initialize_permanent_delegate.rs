@@ -0,0 +1,68 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Initializes the permanent delegate on a new mint.
+///
+/// Fails if the mint has already been initialized, so must be called before
+/// `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Mint account
+pub struct InitializePermanentDelegate<'a, 'b> {
+    /// Mint account.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Authority that may sign for `Transfer`s and `Burn`s on any account.
+    pub delegate: &'b Pubkey,
+}
+
+impl<'a, 'b> InitializePermanentDelegate<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 1] = [self.mint.to_meta_c()];
+
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: instruction_data.as_ptr(),
+            data_len: instruction_data.len() as u64,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.mint], signers)
+    }
+
+    /// Instruction data, as packed by
+    /// `TokenInstruction::InitializePermanentDelegate`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 33] {
+        // - [0    ]: instruction discriminator
+        // - [1..33]: delegate pubkey
+        let mut instruction_data = [0; 33];
+        instruction_data[0] = 35;
+        instruction_data[1..33].copy_from_slice(self.delegate.as_ref());
+        instruction_data
+    }
+}
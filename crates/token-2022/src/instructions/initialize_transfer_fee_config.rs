@@ -0,0 +1,104 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Initializes the transfer fee on a new mint.
+///
+/// Fails if the mint has already been initialized, so must be called before
+/// `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Mint account
+pub struct InitializeTransferFeeConfig<'a, 'b> {
+    /// Mint account.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Pubkey that may update the fees.
+    pub transfer_fee_config_authority: Option<&'b Pubkey>,
+
+    /// Withdraw instructions must be signed by this key.
+    pub withdraw_withheld_authority: Option<&'b Pubkey>,
+
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount, ie. increments of 0.01%.
+    pub transfer_fee_basis_points: u16,
+
+    /// Maximum fee assessed on transfers.
+    pub maximum_fee: u64,
+}
+
+impl<'a, 'b> InitializeTransferFeeConfig<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 1] = [self.mint.to_meta_c()];
+
+        let (instruction_data, data_len) = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: instruction_data.as_ptr(),
+            data_len: data_len as u64,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.mint], signers)
+    }
+
+    /// Instruction data, as packed by
+    /// `TransferFeeInstruction::InitializeTransferFeeConfig`.
+    ///
+    /// Returns the data buffer together with the number of bytes in use.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> ([u8; 78], usize) {
+        // - [0   ]: instruction discriminator
+        // - [1   ]: extension instruction discriminator
+        // - [2.. ]: transfer fee config authority (1 or 33 bytes)
+        // - [..  ]: withdraw withheld authority (1 or 33 bytes)
+        // - [..  ]: transfer fee basis points (2 bytes)
+        // - [..  ]: maximum fee (8 bytes)
+        let mut instruction_data = [0; 78];
+        instruction_data[0] = 26;
+        instruction_data[1] = 0;
+
+        let mut offset = 2;
+
+        for authority in [
+            self.transfer_fee_config_authority,
+            self.withdraw_withheld_authority,
+        ] {
+            if let Some(authority) = authority {
+                instruction_data[offset] = 1;
+                instruction_data[offset + 1..offset + 33].copy_from_slice(authority.as_ref());
+                offset += 33;
+            } else {
+                offset += 1;
+            }
+        }
+
+        instruction_data[offset..offset + 2]
+            .copy_from_slice(&self.transfer_fee_basis_points.to_le_bytes());
+        instruction_data[offset + 2..offset + 10].copy_from_slice(&self.maximum_fee.to_le_bytes());
+
+        (instruction_data, offset + 10)
+    }
+}
@@ -0,0 +1,81 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Initializes a new mint with a transfer hook program.
+///
+/// Fails if the mint has already been initialized, so must be called before
+/// `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Mint account
+pub struct InitializeTransferHook<'a, 'b> {
+    /// Mint account.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Authority that can update the program id.
+    pub authority: Option<&'b Pubkey>,
+
+    /// Program that performs logic during transfers.
+    pub program_id: Option<&'b Pubkey>,
+}
+
+impl<'a, 'b> InitializeTransferHook<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 1] = [self.mint.to_meta_c()];
+
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: instruction_data.as_ptr(),
+            data_len: instruction_data.len() as u64,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.mint], signers)
+    }
+
+    /// Instruction data, as packed by `TransferHookInstruction::Initialize`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 66] {
+        // - [0     ]: instruction discriminator
+        // - [1     ]: extension instruction discriminator
+        // - [2..34 ]: authority pubkey (zeroed if none)
+        // - [34..66]: program id (zeroed if none)
+        let mut instruction_data = [0; 66];
+        instruction_data[0] = 36;
+        instruction_data[1] = 0;
+
+        if let Some(authority) = self.authority {
+            instruction_data[2..34].copy_from_slice(authority.as_ref());
+        }
+
+        if let Some(program_id) = self.program_id {
+            instruction_data[34..66].copy_from_slice(program_id.as_ref());
+        }
+
+        instruction_data
+    }
+}
@@ -0,0 +1,264 @@
+mod initialize_immutable_owner;
+mod initialize_interest_bearing_mint;
+mod initialize_metadata_pointer;
+mod initialize_mint_close_authority;
+mod initialize_non_transferable_mint;
+mod initialize_permanent_delegate;
+mod initialize_transfer_fee_config;
+mod initialize_transfer_hook;
+mod reallocate;
+mod transfer_checked_with_fee;
+
+pub use initialize_immutable_owner::*;
+pub use initialize_interest_bearing_mint::*;
+pub use initialize_metadata_pointer::*;
+pub use initialize_mint_close_authority::*;
+pub use initialize_non_transferable_mint::*;
+pub use initialize_permanent_delegate::*;
+pub use initialize_transfer_fee_config::*;
+pub use initialize_transfer_hook::*;
+pub use reallocate::*;
+pub use transfer_checked_with_fee::*;
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::{self, MockAccount};
+    use solana_program::{
+        entrypoint::ProgramResult, instruction::Instruction, pubkey::Pubkey, system_program,
+    };
+    use spl_token_2022::{
+        extension::{
+            interest_bearing_mint, metadata_pointer, transfer_fee, transfer_hook,
+            ExtensionType as SplExtensionType,
+        },
+        instruction as spl,
+    };
+
+    use super::*;
+    use crate::extension::ExtensionType;
+
+    /// Invoke a builder and return the instruction recorded by the mock.
+    fn invoked(invoke: impl FnOnce() -> ProgramResult) -> Instruction {
+        mock::clear_invoked_instructions();
+        invoke().unwrap();
+
+        let mut invoked = mock::take_invoked_instructions();
+        assert_eq!(invoked.len(), 1);
+        invoked.remove(0).instruction
+    }
+
+    fn account(is_writable: bool, is_signer: bool) -> MockAccount {
+        MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &[])
+            .writable(is_writable)
+            .signer(is_signer)
+    }
+
+    #[test]
+    fn mint_initializers_match_spl_token_2022() {
+        let mint = account(true, false);
+        let (authority, address) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert_eq!(
+            invoked(|| InitializeNonTransferableMint { mint: mint.info() }.invoke()),
+            spl::initialize_non_transferable_mint(&crate::ID, &mint.key()).unwrap()
+        );
+        assert_eq!(
+            invoked(|| InitializePermanentDelegate {
+                mint: mint.info(),
+                delegate: &authority,
+            }
+            .invoke()),
+            spl::initialize_permanent_delegate(&crate::ID, &mint.key(), &authority).unwrap()
+        );
+
+        for close_authority in [Some(&authority), None] {
+            assert_eq!(
+                invoked(|| InitializeMintCloseAuthority {
+                    mint: mint.info(),
+                    close_authority,
+                }
+                .invoke()),
+                spl::initialize_mint_close_authority(&crate::ID, &mint.key(), close_authority)
+                    .unwrap()
+            );
+        }
+
+        for rate_authority in [Some(&authority), None] {
+            assert_eq!(
+                invoked(|| InitializeInterestBearingMint {
+                    mint: mint.info(),
+                    rate_authority,
+                    rate: -250,
+                }
+                .invoke()),
+                interest_bearing_mint::instruction::initialize(
+                    &crate::ID,
+                    &mint.key(),
+                    rate_authority.copied(),
+                    -250
+                )
+                .unwrap()
+            );
+        }
+
+        for (authority, address) in [
+            (Some(&authority), Some(&address)),
+            (Some(&authority), None),
+            (None, Some(&address)),
+            (None, None),
+        ] {
+            assert_eq!(
+                invoked(|| InitializeMetadataPointer {
+                    mint: mint.info(),
+                    authority,
+                    metadata_address: address,
+                }
+                .invoke()),
+                metadata_pointer::instruction::initialize(
+                    &crate::ID,
+                    &mint.key(),
+                    authority.copied(),
+                    address.copied()
+                )
+                .unwrap()
+            );
+            assert_eq!(
+                invoked(|| InitializeTransferHook {
+                    mint: mint.info(),
+                    authority,
+                    program_id: address,
+                }
+                .invoke()),
+                transfer_hook::instruction::initialize(
+                    &crate::ID,
+                    &mint.key(),
+                    authority.copied(),
+                    address.copied()
+                )
+                .unwrap()
+            );
+            assert_eq!(
+                invoked(|| InitializeTransferFeeConfig {
+                    mint: mint.info(),
+                    transfer_fee_config_authority: authority,
+                    withdraw_withheld_authority: address,
+                    transfer_fee_basis_points: 25,
+                    maximum_fee: 5_000,
+                }
+                .invoke()),
+                transfer_fee::instruction::initialize_transfer_fee_config(
+                    &crate::ID,
+                    &mint.key(),
+                    authority,
+                    address,
+                    25,
+                    5_000
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn account_instructions_match_spl_token_2022() {
+        let token_account = account(true, false);
+
+        assert_eq!(
+            invoked(|| InitializeImmutableOwner {
+                account: token_account.info(),
+            }
+            .invoke()),
+            spl::initialize_immutable_owner(&crate::ID, &token_account.key()).unwrap()
+        );
+
+        let (mint, destination) = (account(false, false), account(true, false));
+        let payer = account(true, true);
+        let system_program = MockAccount::new(system_program::ID, Pubkey::default(), 0, &[]);
+        let extension_types = [ExtensionType::ImmutableOwner, ExtensionType::MemoTransfer];
+        let spl_extension_types = extension_types
+            .map(|extension_type| SplExtensionType::try_from(extension_type as u16).unwrap());
+
+        // single owner, and multisignature owner with two signers
+        let signers = [account(false, true), account(false, true)];
+        let single_owner = account(false, true);
+        let multisig = account(false, false);
+
+        for (owner, multisig_signers) in [
+            (&single_owner, &[][..]),
+            (&multisig, &[signers[0].info(), signers[1].info()][..]),
+        ] {
+            let signer_keys = multisig_signers
+                .iter()
+                .map(|signer| signer.key())
+                .collect::<std::vec::Vec<_>>();
+
+            assert_eq!(
+                invoked(|| Reallocate {
+                    account: token_account.info(),
+                    payer: payer.info(),
+                    system_program: system_program.info(),
+                    owner: owner.info(),
+                    multisig_signers,
+                    extension_types: &extension_types,
+                }
+                .invoke()),
+                spl::reallocate(
+                    &crate::ID,
+                    &token_account.key(),
+                    &payer.key(),
+                    &owner.key(),
+                    &signer_keys,
+                    &spl_extension_types
+                )
+                .unwrap()
+            );
+            assert_eq!(
+                invoked(|| TransferCheckedWithFee {
+                    source: token_account.info(),
+                    mint: mint.info(),
+                    destination: destination.info(),
+                    authority: owner.info(),
+                    multisig_signers,
+                    amount: 1_000,
+                    decimals: 6,
+                    fee: 3,
+                }
+                .invoke()),
+                transfer_fee::instruction::transfer_checked_with_fee(
+                    &crate::ID,
+                    &token_account.key(),
+                    &mint.key(),
+                    &destination.key(),
+                    &owner.key(),
+                    &signer_keys,
+                    1_000,
+                    6,
+                    3
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn reallocate_bounds_the_extension_types() {
+        let account = account(true, true);
+        let extension_types = [ExtensionType::ImmutableOwner; ExtensionType::COUNT + 1];
+
+        assert_eq!(
+            Reallocate {
+                account: account.info(),
+                payer: account.info(),
+                system_program: account.info(),
+                owner: account.info(),
+                multisig_signers: &[],
+                extension_types: &extension_types,
+            }
+            .instruction_data()
+            .err(),
+            Some(solana_program::program_error::ProgramError::InvalidArgument)
+        );
+    }
+}
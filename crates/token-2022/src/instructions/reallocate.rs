@@ -0,0 +1,104 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use nostd_token_program::multisig::invoke_signed_with_metas;
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::extension::ExtensionType;
+
+/// Maximum number of extension types in a single `Reallocate` instruction.
+const MAX_EXTENSION_TYPES: usize = ExtensionType::COUNT;
+
+/// Checks that a token account has enough space for the given extensions,
+/// reallocating and topping up its lamports from the payer if needed.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Token account
+///   1. `[WRITE, SIGNER]` Payer account
+///   2. `[]` System program
+///   3. `[SIGNER]` Token account owner, or
+///      `[]` multisignature account
+///   4. ..4+M `[SIGNER]` M signer accounts of the multisignature
+pub struct Reallocate<'a, 'b> {
+    /// Token account.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Payer account.
+    pub payer: &'a NoStdAccountInfo,
+
+    /// System program.
+    pub system_program: &'a NoStdAccountInfo,
+
+    /// Token account owner.
+    pub owner: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `owner` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+
+    /// New extension types to include in the reallocated account.
+    pub extension_types: &'b [ExtensionType],
+}
+
+impl<'a, 'b> Reallocate<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let (instruction_data, data_len) = self.instruction_data()?;
+
+        invoke_signed_with_metas(
+            &crate::ID,
+            [
+                self.account.to_meta_c(),
+                self.payer.to_meta_c_signer(),
+                self.system_program.to_meta_c(),
+                self.owner.to_meta_c_signer(),
+            ],
+            &[self.account, self.payer, self.system_program, self.owner],
+            self.multisig_signers,
+            &instruction_data[..data_len],
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::Reallocate`.
+    ///
+    /// Returns the data buffer together with the number of bytes in use, or
+    /// an error if there are more extension types than known extensions.
+    #[inline(always)]
+    pub fn instruction_data(
+        &self,
+    ) -> Result<([u8; 1 + 2 * MAX_EXTENSION_TYPES], usize), ProgramError> {
+        if self.extension_types.len() > MAX_EXTENSION_TYPES {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // - [0  ]: instruction discriminator
+        // - [1..]: extension types (2 bytes each)
+        let mut instruction_data = [0; 1 + 2 * MAX_EXTENSION_TYPES];
+        instruction_data[0] = 29;
+
+        for (index, extension_type) in self.extension_types.iter().enumerate() {
+            let offset = 1 + 2 * index;
+            instruction_data[offset..offset + 2]
+                .copy_from_slice(&(*extension_type as u16).to_le_bytes());
+        }
+
+        Ok((instruction_data, 1 + 2 * self.extension_types.len()))
+    }
+}
@@ -0,0 +1,90 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use nostd_token_program::multisig::invoke_signed_with_authority;
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Transfers tokens from one account to another either directly or via a
+/// delegate, asserting the token mint, decimals and the expected fee.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Source account
+///   1. `[]` Token mint
+///   2. `[WRITE]` Destination account
+///   3. `[SIGNER]` Source account owner or delegate, or
+///      `[]` multisignature account
+///   4. ..4+M `[SIGNER]` M signer accounts of the multisignature
+pub struct TransferCheckedWithFee<'a, 'b> {
+    /// Source account.
+    pub source: &'a NoStdAccountInfo,
+
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Destination account.
+    pub destination: &'a NoStdAccountInfo,
+
+    /// Source account owner or delegate.
+    pub authority: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `authority` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+
+    /// Amount of tokens to transfer.
+    pub amount: u64,
+
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+
+    /// Expected fee assessed on this transfer, calculated off-chain based on
+    /// the transfer fee configuration of the mint.
+    pub fee: u64,
+}
+
+impl<'a, 'b> TransferCheckedWithFee<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.source, self.mint, self.destination, self.authority],
+            self.multisig_signers,
+            &self.instruction_data(),
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by
+    /// `TransferFeeInstruction::TransferCheckedWithFee`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 19] {
+        // - [0     ]: instruction discriminator
+        // - [1     ]: extension instruction discriminator
+        // - [2..10 ]: amount
+        // - [10    ]: decimals
+        // - [11..19]: fee
+        let mut instruction_data = [0; 19];
+        instruction_data[0] = 26;
+        instruction_data[1] = 1;
+        instruction_data[2..10].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[10] = self.decimals;
+        instruction_data[11..19].copy_from_slice(&self.fee.to_le_bytes());
+        instruction_data
+    }
+}
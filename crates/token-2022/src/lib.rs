@@ -0,0 +1,9 @@
+#![no_std]
+
+use solana_nostd_entrypoint::solana_program::declare_id;
+
+pub mod extension;
+pub mod instructions;
+pub mod state;
+
+declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
@@ -0,0 +1,197 @@
+//! Base state of Token-2022 mints and token accounts.
+//!
+//! The base layouts are the ones of the token program, but Token-2022
+//! accounts may be followed by an account type and extensions, so the views
+//! of `nostd_token_program::state` reject them on their length. These views
+//! check the account type instead and only expose the base state.
+
+use core::ops::Deref;
+
+use nostd_entrypoint_invoke::state::StateRef;
+use nostd_token_program::state::{Account, Mint};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::program_error::ProgramError;
+
+use crate::extension::{extensions, AccountType};
+
+/// Borrow the base state of an initialized mint owned by Token-2022.
+pub fn mint_from_account_info(
+    account_info: &NoStdAccountInfo,
+) -> Result<impl Deref<Target = Mint> + '_, ProgramError> {
+    if *account_info.owner() != crate::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let data = account_info
+        .try_borrow_data()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+
+    mint_from_bytes(&data)?;
+
+    Ok(unsafe { StateRef::<_, Mint>::new(data) })
+}
+
+/// View the base state of the bytes of an initialized mint, with or without
+/// extensions.
+pub fn mint_from_bytes(data: &[u8]) -> Result<&Mint, ProgramError> {
+    // checks the length, the padding and the account type
+    extensions(data, AccountType::Mint)?;
+
+    Mint::from_bytes(&data[..Mint::LEN])
+}
+
+/// Borrow the base state of an initialized token account owned by
+/// Token-2022.
+pub fn account_from_account_info(
+    account_info: &NoStdAccountInfo,
+) -> Result<impl Deref<Target = Account> + '_, ProgramError> {
+    if *account_info.owner() != crate::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let data = account_info
+        .try_borrow_data()
+        .ok_or(ProgramError::AccountBorrowFailed)?;
+
+    account_from_bytes(&data)?;
+
+    Ok(unsafe { StateRef::<_, Account>::new(data) })
+}
+
+/// View the base state of the bytes of an initialized token account, with
+/// or without extensions.
+pub fn account_from_bytes(data: &[u8]) -> Result<&Account, ProgramError> {
+    // checks the length and the account type
+    extensions(data, AccountType::Account)?;
+
+    Account::from_bytes(&data[..Account::LEN])
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::MockAccount;
+    use solana_program::{program_option::COption, pubkey::Pubkey};
+    use spl_token_2022::{
+        extension::{
+            mint_close_authority::MintCloseAuthority, transfer_fee::TransferFeeAmount,
+            BaseStateWithExtensionsMut, ExtensionType as SplExtensionType, StateWithExtensionsMut,
+        },
+        state,
+    };
+    use std::{vec, vec::Vec};
+
+    use super::*;
+
+    fn mint_with_close_authority() -> Vec<u8> {
+        let len = SplExtensionType::try_calculate_account_len::<state::Mint>(&[
+            SplExtensionType::MintCloseAuthority,
+        ])
+        .unwrap();
+        let mut data = vec![0; len];
+        let mut mint =
+            StateWithExtensionsMut::<state::Mint>::unpack_uninitialized(&mut data).unwrap();
+
+        mint.init_extension::<MintCloseAuthority>(true).unwrap();
+        mint.base = state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 42,
+            decimals: 9,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+
+        data
+    }
+
+    fn account_with_transfer_fee_amount(mint: Pubkey) -> Vec<u8> {
+        let len = SplExtensionType::try_calculate_account_len::<state::Account>(&[
+            SplExtensionType::TransferFeeAmount,
+        ])
+        .unwrap();
+        let mut data = vec![0; len];
+        let mut account =
+            StateWithExtensionsMut::<state::Account>::unpack_uninitialized(&mut data).unwrap();
+
+        account.init_extension::<TransferFeeAmount>(true).unwrap();
+        account.base = state::Account {
+            mint,
+            amount: 1_000,
+            state: state::AccountState::Initialized,
+            ..Default::default()
+        };
+        account.pack_base();
+        account.init_account_type().unwrap();
+
+        data
+    }
+
+    #[test]
+    fn reads_the_base_state_of_accounts_with_extensions() {
+        let mint_data = mint_with_close_authority();
+
+        // the token program view only accepts the base length
+        assert!(Mint::from_bytes(&mint_data).is_err());
+
+        let mint = mint_from_bytes(&mint_data).unwrap();
+        assert_eq!(mint.decimals(), 9);
+        assert_eq!(mint.supply(), 42);
+
+        let mint_key = Pubkey::new_unique();
+        let account_data = account_with_transfer_fee_amount(mint_key);
+        let account = account_from_bytes(&account_data).unwrap();
+        assert_eq!(*account.mint(), mint_key);
+        assert_eq!(account.amount(), 1_000);
+
+        // base states without extensions
+        assert_eq!(
+            mint_from_bytes(&mint_data[..Mint::LEN]).unwrap().decimals(),
+            9
+        );
+        assert!(account_from_bytes(&account_data[..Account::LEN]).is_ok());
+    }
+
+    #[test]
+    fn checks_the_account_type() {
+        let mint_data = mint_with_close_authority();
+        let account_data = account_with_transfer_fee_amount(Pubkey::new_unique());
+
+        assert_eq!(
+            account_from_bytes(&mint_data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            mint_from_bytes(&account_data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // a token account is not a mint even when its type byte is patched
+        let mut patched = account_data.clone();
+        patched[Account::LEN] = AccountType::Mint as u8;
+        assert!(mint_from_bytes(&patched).is_err());
+
+        let uninitialized = vec![0; Mint::LEN];
+        assert_eq!(
+            mint_from_bytes(&uninitialized).err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+    }
+
+    #[test]
+    fn checks_the_owner() {
+        let mint_data = mint_with_close_authority();
+
+        let mint = MockAccount::new(Pubkey::new_unique(), crate::ID, 1, &mint_data);
+        assert_eq!(mint_from_account_info(mint.info()).unwrap().decimals(), 9);
+
+        let legacy_mint =
+            MockAccount::new(Pubkey::new_unique(), nostd_token_program::ID, 1, &mint_data);
+        assert_eq!(
+            mint_from_account_info(legacy_mint.info()).err(),
+            Some(ProgramError::IncorrectProgramId)
+        );
+    }
+}
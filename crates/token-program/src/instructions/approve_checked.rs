@@ -0,0 +1,82 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::multisig::invoke_signed_with_authority;
+
+/// Approves a delegate to transfer up to a maximum number of tokens from an
+/// account, asserting the token mint and decimals.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Source account
+///   1. `[]` Token mint
+///   2. `[]` Delegate
+///   3. `[SIGNER]` Source account owner, or
+///      `[]` multisignature account
+///   4. ..4+M `[SIGNER]` M signer accounts of the multisignature
+pub struct ApproveChecked<'a, 'b> {
+    /// Source account.
+    pub source: &'a NoStdAccountInfo,
+
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Delegate.
+    pub delegate: &'a NoStdAccountInfo,
+
+    /// Source account owner.
+    pub owner: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `owner` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+
+    /// Amount of tokens the delegate is approved for.
+    pub amount: u64,
+
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl<'a, 'b> ApproveChecked<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.source, self.mint, self.delegate, self.owner],
+            self.multisig_signers,
+            &self.instruction_data(),
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::ApproveChecked`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 10] {
+        // - [0    ]: instruction discriminator
+        // - [1..9 ]: amount
+        // - [9    ]: decimals
+        let mut instruction_data = [0; 10];
+        instruction_data[0] = 13;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+        instruction_data
+    }
+}
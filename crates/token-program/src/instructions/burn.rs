@@ -0,0 +1,72 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::multisig::invoke_signed_with_authority;
+
+/// Burns tokens by removing them from an account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Account to burn from
+///   1. `[WRITE]` Token mint
+///   2. `[SIGNER]` Account owner or delegate, or
+///      `[]` multisignature account
+///   3. ..3+M `[SIGNER]` M signer accounts of the multisignature
+pub struct Burn<'a, 'b> {
+    /// Account to burn from.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Account owner or delegate.
+    pub authority: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `authority` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+
+    /// Amount of tokens to burn.
+    pub amount: u64,
+}
+
+impl<'a, 'b> Burn<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.account, self.mint, self.authority],
+            self.multisig_signers,
+            &self.instruction_data(),
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::Burn`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 9] {
+        // - [0   ]: instruction discriminator
+        // - [1..9]: amount
+        let mut instruction_data = [0; 9];
+        instruction_data[0] = 8;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data
+    }
+}
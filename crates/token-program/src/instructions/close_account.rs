@@ -0,0 +1,66 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::multisig::invoke_signed_with_authority;
+
+/// Closes an account by transferring all its SOL to the destination account.
+///
+/// Non-native accounts may only be closed if their token amount is zero.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Account to close
+///   1. `[WRITE]` Destination account
+///   2. `[SIGNER]` Account close authority, or
+///      `[]` multisignature account
+///   3. ..3+M `[SIGNER]` M signer accounts of the multisignature
+pub struct CloseAccount<'a, 'b> {
+    /// Account to close.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Destination account.
+    pub destination: &'a NoStdAccountInfo,
+
+    /// Account close authority.
+    pub authority: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `authority` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+}
+
+impl<'a, 'b> CloseAccount<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.account, self.destination, self.authority],
+            self.multisig_signers,
+            &self.instruction_data(),
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::CloseAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [9]
+    }
+}
@@ -0,0 +1,64 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::multisig::invoke_signed_with_authority;
+
+/// Freezes an initialized account using the mint's freeze authority.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Account to freeze
+///   1. `[]` Token mint
+///   2. `[SIGNER]` Mint freeze authority, or
+///      `[]` multisignature account
+///   3. ..3+M `[SIGNER]` M signer accounts of the multisignature
+pub struct FreezeAccount<'a, 'b> {
+    /// Account to freeze.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Mint freeze authority.
+    pub freeze_authority: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `freeze_authority` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+}
+
+impl<'a, 'b> FreezeAccount<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.account, self.mint, self.freeze_authority],
+            self.multisig_signers,
+            &self.instruction_data(),
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::FreezeAccount`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [10]
+    }
+}
@@ -0,0 +1,69 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Initializes a new account to hold tokens, without requiring the Rent
+/// sysvar.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Account to initialize
+///   1. `[]` Token mint
+pub struct InitializeAccount3<'a, 'b> {
+    /// Account to initialize.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Owner of the new account.
+    pub owner: &'b Pubkey,
+}
+
+impl<'a, 'b> InitializeAccount3<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 2] = [self.account.to_meta_c(), self.mint.to_meta_c()];
+
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 2,
+            data: instruction_data.as_ptr(),
+            data_len: 33,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.account, self.mint], signers)
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::InitializeAccount3`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 33] {
+        // - [0    ]: instruction discriminator
+        // - [1..33]: owner pubkey
+        let mut instruction_data = [0; 33];
+        instruction_data[0] = 18;
+        instruction_data[1..33].copy_from_slice(self.owner.as_ref());
+        instruction_data
+    }
+}
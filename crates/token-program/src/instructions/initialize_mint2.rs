@@ -0,0 +1,83 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Initializes a new mint, without requiring the Rent sysvar.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Mint account
+pub struct InitializeMint2<'a, 'b> {
+    /// Mint account.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+
+    /// The authority/multisignature to mint tokens.
+    pub mint_authority: &'b Pubkey,
+
+    /// The freeze authority/multisignature of the mint.
+    pub freeze_authority: Option<&'b Pubkey>,
+}
+
+impl<'a, 'b> InitializeMint2<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 1] = [self.mint.to_meta_c()];
+
+        let (instruction_data, data_len) = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: instruction_data.as_ptr(),
+            data_len: data_len as u64,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.mint], signers)
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::InitializeMint2`.
+    ///
+    /// Returns the data buffer together with the number of bytes in use.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> ([u8; 67], usize) {
+        // - [0     ]: instruction discriminator
+        // - [1     ]: decimals
+        // - [2..34 ]: mint authority pubkey
+        // - [34    ]: freeze authority presence flag
+        // - [35..67]: freeze authority pubkey (optional)
+        let mut instruction_data = [0; 67];
+        instruction_data[0] = 20;
+        instruction_data[1] = self.decimals;
+        instruction_data[2..34].copy_from_slice(self.mint_authority.as_ref());
+
+        if let Some(freeze_authority) = self.freeze_authority {
+            instruction_data[34] = 1;
+            instruction_data[35..67].copy_from_slice(freeze_authority.as_ref());
+            (instruction_data, 67)
+        } else {
+            (instruction_data, 35)
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use core::mem::MaybeUninit;
+
+use nostd_entrypoint_invoke::{invoke_signed_slice, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::multisig::MAX_SIGNERS;
+
+/// Initializes a multisignature account with N provided signers, without
+/// requiring the Rent sysvar.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Multisignature account to initialize
+///   1. ..1+N `[]` Signer accounts, must equal to N where `1 <= N <= 11`
+pub struct InitializeMultisig2<'a, 'b> {
+    /// Multisignature account to initialize.
+    pub multisig: &'a NoStdAccountInfo,
+
+    /// Signer accounts.
+    pub signers: &'b [&'a NoStdAccountInfo],
+
+    /// The number of signers (M) required to validate this multisignature
+    /// account.
+    pub m: u8,
+}
+
+impl<'a, 'b> InitializeMultisig2<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        if self.signers.len() > MAX_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT: MaybeUninit<AccountMetaC> = MaybeUninit::<AccountMetaC>::uninit();
+        let mut account_metas = [UNINIT; 1 + MAX_SIGNERS];
+        let mut account_infos = [self.multisig; 1 + MAX_SIGNERS];
+
+        // account metadata
+        account_metas[0].write(self.multisig.to_meta_c());
+
+        for (index, signer) in self.signers.iter().enumerate() {
+            account_metas[1 + index].write(signer.to_meta_c());
+            account_infos[1 + index] = signer;
+        }
+
+        let accounts_len = 1 + self.signers.len();
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr() as *const AccountMetaC,
+            accounts_len: accounts_len as u64,
+            data: instruction_data.as_ptr(),
+            data_len: 2,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_slice::<{ 1 + MAX_SIGNERS }>(
+            &instruction,
+            &account_infos[..accounts_len],
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::InitializeMultisig2`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 2] {
+        // - [0]: instruction discriminator
+        // - [1]: number of required signers
+        [19, self.m]
+    }
+}
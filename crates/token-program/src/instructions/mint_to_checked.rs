@@ -0,0 +1,77 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::multisig::invoke_signed_with_authority;
+
+/// Mints new tokens to an account, asserting the token mint and decimals.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Token mint
+///   1. `[WRITE]` Account to mint tokens to
+///   2. `[SIGNER]` Mint authority, or
+///      `[]` multisignature account
+///   3. ..3+M `[SIGNER]` M signer accounts of the multisignature
+pub struct MintToChecked<'a, 'b> {
+    /// Token mint.
+    pub mint: &'a NoStdAccountInfo,
+
+    /// Account to mint tokens to.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Mint authority.
+    pub mint_authority: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `mint_authority` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+
+    /// Amount of new tokens to mint.
+    pub amount: u64,
+
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl<'a, 'b> MintToChecked<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.mint, self.account, self.mint_authority],
+            self.multisig_signers,
+            &self.instruction_data(),
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::MintToChecked`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 10] {
+        // - [0    ]: instruction discriminator
+        // - [1..9 ]: amount
+        // - [9    ]: decimals
+        let mut instruction_data = [0; 10];
+        instruction_data[0] = 14;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+        instruction_data
+    }
+}
@@ -0,0 +1,319 @@
+mod approve;
+mod approve_checked;
+mod burn;
+mod burn_checked;
+mod close_account;
+mod freeze_account;
+mod initialize_account3;
+mod initialize_mint2;
+mod initialize_multisig2;
+mod mint_to;
+mod mint_to_checked;
+mod revoke;
+mod set_authority;
+mod sync_native;
+mod thaw_account;
+mod transfer;
+mod transfer_checked;
+
+pub use approve::*;
+pub use approve_checked::*;
+pub use burn::*;
+pub use burn_checked::*;
+pub use close_account::*;
+pub use freeze_account::*;
+pub use initialize_account3::*;
+pub use initialize_mint2::*;
+pub use initialize_multisig2::*;
+pub use mint_to::*;
+pub use mint_to_checked::*;
+pub use revoke::*;
+pub use set_authority::*;
+pub use sync_native::*;
+pub use thaw_account::*;
+pub use transfer::*;
+pub use transfer_checked::*;
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use nostd_entrypoint_invoke::mock::MockAccount;
+    use solana_program::pubkey::Pubkey;
+    use spl_token::instruction::{self as spl, TokenInstruction};
+
+    use super::*;
+
+    fn pack(instruction: TokenInstruction) -> Vec<u8> {
+        instruction.pack()
+    }
+
+    #[test]
+    fn amount_instructions_match_spl_token() {
+        let account = MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &[]);
+        let info = account.info();
+
+        assert_eq!(
+            Transfer {
+                source: info,
+                destination: info,
+                authority: info,
+                multisig_signers: &[],
+                amount: 1,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::Transfer { amount: 1 })
+        );
+        assert_eq!(
+            TransferChecked {
+                source: info,
+                mint: info,
+                destination: info,
+                authority: info,
+                multisig_signers: &[],
+                amount: 2,
+                decimals: 9,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::TransferChecked {
+                amount: 2,
+                decimals: 9
+            })
+        );
+        assert_eq!(
+            Approve {
+                source: info,
+                delegate: info,
+                owner: info,
+                multisig_signers: &[],
+                amount: 3,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::Approve { amount: 3 })
+        );
+        assert_eq!(
+            ApproveChecked {
+                source: info,
+                mint: info,
+                delegate: info,
+                owner: info,
+                multisig_signers: &[],
+                amount: 4,
+                decimals: 6,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::ApproveChecked {
+                amount: 4,
+                decimals: 6
+            })
+        );
+        assert_eq!(
+            MintTo {
+                mint: info,
+                account: info,
+                mint_authority: info,
+                multisig_signers: &[],
+                amount: 5,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::MintTo { amount: 5 })
+        );
+        assert_eq!(
+            MintToChecked {
+                mint: info,
+                account: info,
+                mint_authority: info,
+                multisig_signers: &[],
+                amount: 6,
+                decimals: 2,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::MintToChecked {
+                amount: 6,
+                decimals: 2
+            })
+        );
+        assert_eq!(
+            Burn {
+                account: info,
+                mint: info,
+                authority: info,
+                multisig_signers: &[],
+                amount: 7,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::Burn { amount: 7 })
+        );
+        assert_eq!(
+            BurnChecked {
+                account: info,
+                mint: info,
+                authority: info,
+                multisig_signers: &[],
+                amount: 8,
+                decimals: 0,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::BurnChecked {
+                amount: 8,
+                decimals: 0
+            })
+        );
+    }
+
+    #[test]
+    fn account_instructions_match_spl_token() {
+        let account = MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &[]);
+        let info = account.info();
+
+        assert_eq!(
+            CloseAccount {
+                account: info,
+                destination: info,
+                authority: info,
+                multisig_signers: &[],
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::CloseAccount)
+        );
+        assert_eq!(
+            FreezeAccount {
+                account: info,
+                mint: info,
+                freeze_authority: info,
+                multisig_signers: &[],
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::FreezeAccount)
+        );
+        assert_eq!(
+            ThawAccount {
+                account: info,
+                mint: info,
+                freeze_authority: info,
+                multisig_signers: &[],
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::ThawAccount)
+        );
+        assert_eq!(
+            Revoke {
+                source: info,
+                owner: info,
+                multisig_signers: &[],
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::Revoke)
+        );
+        assert_eq!(
+            SyncNative { account: info }.instruction_data().to_vec(),
+            pack(TokenInstruction::SyncNative)
+        );
+        assert_eq!(
+            InitializeMultisig2 {
+                multisig: info,
+                signers: &[info, info],
+                m: 2,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::InitializeMultisig2 { m: 2 })
+        );
+    }
+
+    #[test]
+    fn initialize_instructions_match_spl_token() {
+        let account = MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &[]);
+        let info = account.info();
+        let owner = Pubkey::new_unique();
+        let freeze_authority = Pubkey::new_unique();
+
+        assert_eq!(
+            InitializeAccount3 {
+                account: info,
+                mint: info,
+                owner: &owner,
+            }
+            .instruction_data()
+            .to_vec(),
+            pack(TokenInstruction::InitializeAccount3 { owner })
+        );
+
+        for freeze_authority in [None, Some(&freeze_authority)] {
+            let (data, len) = InitializeMint2 {
+                mint: info,
+                decimals: 9,
+                mint_authority: &owner,
+                freeze_authority,
+            }
+            .instruction_data();
+
+            assert_eq!(
+                data[..len],
+                pack(TokenInstruction::InitializeMint2 {
+                    decimals: 9,
+                    mint_authority: owner,
+                    freeze_authority: freeze_authority.copied().into(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn set_authority_matches_spl_token() {
+        let account = MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &[]);
+        let info = account.info();
+        let new_authority = Pubkey::new_unique();
+
+        let authority_types = [
+            (AuthorityType::MintTokens, spl::AuthorityType::MintTokens),
+            (
+                AuthorityType::FreezeAccount,
+                spl::AuthorityType::FreezeAccount,
+            ),
+            (
+                AuthorityType::AccountOwner,
+                spl::AuthorityType::AccountOwner,
+            ),
+            (
+                AuthorityType::CloseAccount,
+                spl::AuthorityType::CloseAccount,
+            ),
+        ];
+
+        for (authority_type, spl_authority_type) in authority_types {
+            for new_authority in [None, Some(&new_authority)] {
+                let (data, len) = SetAuthority {
+                    account: info,
+                    authority: info,
+                    multisig_signers: &[],
+                    authority_type,
+                    new_authority,
+                }
+                .instruction_data();
+
+                assert_eq!(
+                    data[..len],
+                    pack(TokenInstruction::SetAuthority {
+                        authority_type: spl_authority_type.clone(),
+                        new_authority: new_authority.copied().into(),
+                    })
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::multisig::invoke_signed_with_authority;
+
+/// Revokes the delegate's authority.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Source account
+///   1. `[SIGNER]` Source account owner, or
+///      `[]` multisignature account
+///   2. ..2+M `[SIGNER]` M signer accounts of the multisignature
+pub struct Revoke<'a, 'b> {
+    /// Source account.
+    pub source: &'a NoStdAccountInfo,
+
+    /// Source account owner.
+    pub owner: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `owner` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+}
+
+impl<'a, 'b> Revoke<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.source, self.owner],
+            self.multisig_signers,
+            &self.instruction_data(),
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::Revoke`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [5]
+    }
+}
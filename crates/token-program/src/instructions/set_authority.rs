@@ -0,0 +1,101 @@
+use nostd_entrypoint_invoke::{PdaSigners, Signer};
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::multisig::invoke_signed_with_authority;
+
+/// Specifies the authority type for `SetAuthority` instructions.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorityType {
+    /// Authority to mint new tokens.
+    MintTokens,
+
+    /// Authority to freeze any account associated with the mint.
+    FreezeAccount,
+
+    /// Owner of a given token account.
+    AccountOwner,
+
+    /// Authority to close a token account.
+    CloseAccount,
+}
+
+/// Sets a new authority of a mint or account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Mint or account to change the authority of
+///   1. `[SIGNER]` Current authority, or
+///      `[]` multisignature account
+///   2. ..2+M `[SIGNER]` M signer accounts of the multisignature
+pub struct SetAuthority<'a, 'b> {
+    /// Mint or account to change the authority of.
+    pub account: &'a NoStdAccountInfo,
+
+    /// Current authority.
+    pub authority: &'a NoStdAccountInfo,
+
+    /// Signer accounts when `authority` is a multisignature account.
+    pub multisig_signers: &'b [&'a NoStdAccountInfo],
+
+    /// The type of authority to update.
+    pub authority_type: AuthorityType,
+
+    /// The new authority.
+    pub new_authority: Option<&'b Pubkey>,
+}
+
+impl<'a, 'b> SetAuthority<'a, 'b> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        let (instruction_data, data_len) = self.instruction_data();
+
+        invoke_signed_with_authority(
+            &crate::ID,
+            &[self.account, self.authority],
+            self.multisig_signers,
+            &instruction_data[..data_len],
+            signers,
+        )
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::SetAuthority`.
+    ///
+    /// Returns the data buffer together with the number of bytes in use.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> ([u8; 35], usize) {
+        // - [0    ]: instruction discriminator
+        // - [1    ]: authority type
+        // - [2    ]: new authority presence flag
+        // - [3..35]: new authority pubkey (optional)
+        let mut instruction_data = [0; 35];
+        instruction_data[0] = 6;
+        instruction_data[1] = self.authority_type as u8;
+
+        if let Some(new_authority) = self.new_authority {
+            instruction_data[2] = 1;
+            instruction_data[3..35].copy_from_slice(new_authority.as_ref());
+            (instruction_data, 35)
+        } else {
+            (instruction_data, 3)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use nostd_entrypoint_invoke::{invoke_signed_with, PdaSigners, Signer};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Given a wrapped / native token account (a special token account that
+/// holds SOL), updates its amount field based on the account's underlying
+/// `lamports`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Native token account
+pub struct SyncNative<'a> {
+    /// Native token account.
+    pub account: &'a NoStdAccountInfo,
+}
+
+impl<'a> SyncNative<'a> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::NONE)
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, program_id: &Pubkey, signers: &[&[&[u8]]]) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::new(program_id, signers))
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed_with_signers(
+        &self,
+        program_id: &Pubkey,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.invoke_signed_with(PdaSigners::from_signers(program_id, signers))
+    }
+
+    pub fn invoke_signed_with(&self, signers: PdaSigners) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMetaC; 1] = [self.account.to_meta_c()];
+
+        let instruction_data = self.instruction_data();
+
+        let instruction = InstructionC {
+            accounts: account_metas.as_ptr(),
+            accounts_len: 1,
+            data: instruction_data.as_ptr(),
+            data_len: 1,
+            program_id: &crate::ID,
+        };
+
+        invoke_signed_with(&instruction, &[self.account], signers)
+    }
+
+    /// Instruction data, as packed by `TokenInstruction::SyncNative`.
+    #[inline(always)]
+    pub fn instruction_data(&self) -> [u8; 1] {
+        [17]
+    }
+}
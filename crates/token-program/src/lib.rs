@@ -0,0 +1,9 @@
+#![no_std]
+
+use solana_nostd_entrypoint::solana_program::declare_id;
+
+pub mod instructions;
+pub mod multisig;
+pub mod state;
+
+declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
@@ -0,0 +1,152 @@
+use core::mem::MaybeUninit;
+
+use nostd_entrypoint_invoke::{invoke_signed_slice, PdaSigners};
+use solana_nostd_entrypoint::{AccountMetaC, InstructionC, NoStdAccountInfo};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+/// Maximum number of multisignature signers.
+pub const MAX_SIGNERS: usize = 11;
+
+/// Maximum number of accounts of an instruction signed by an authority,
+/// including the multisignature signers.
+const MAX_ACCOUNTS: usize = 4 + MAX_SIGNERS;
+
+/// Invoke an instruction whose last account is the authority signing it.
+///
+/// When `multisig_signers` is empty the authority is passed as a signer.
+/// Otherwise the authority is a multisignature account: it is passed as a
+/// non-signer and followed by the signer accounts.
+///
+/// The authority is always a non-signer with multisignature signers, even if
+/// it signed the transaction, like the `spl_token::instruction` builders that
+/// pass it as `AccountMeta::new_readonly(owner, signer_pubkeys.is_empty())`.
+#[inline(always)]
+pub fn invoke_signed_with_authority<const ACCOUNTS: usize>(
+    program_id: &Pubkey,
+    accounts: &[&NoStdAccountInfo; ACCOUNTS],
+    multisig_signers: &[&NoStdAccountInfo],
+    instruction_data: &[u8],
+    signers: PdaSigners,
+) -> ProgramResult {
+    // account metadata
+    let account_metas: [AccountMetaC; ACCOUNTS] = core::array::from_fn(|index| {
+        if index == ACCOUNTS - 1 {
+            accounts[index].to_meta_c_signer()
+        } else {
+            accounts[index].to_meta_c()
+        }
+    });
+
+    invoke_signed_with_metas(
+        program_id,
+        account_metas,
+        accounts,
+        multisig_signers,
+        instruction_data,
+        signers,
+    )
+}
+
+/// Invoke an instruction whose last account is the authority signing it,
+/// with the given account metadata.
+///
+/// This function is like [`invoke_signed_with_authority`] except that the
+/// metadata of every account is provided, the authority being a signer. It
+/// is marked as a non-signer when `multisig_signers` is not empty.
+///
+/// It serves instructions whose other accounts are not all plain
+/// [`to_meta_c`](NoStdAccountInfo::to_meta_c) metas, such as the Token-2022
+/// `Reallocate` instruction whose payer also signs, while keeping the
+/// multisignature handling in one place.
+pub fn invoke_signed_with_metas<const ACCOUNTS: usize>(
+    program_id: &Pubkey,
+    mut account_metas: [AccountMetaC; ACCOUNTS],
+    accounts: &[&NoStdAccountInfo; ACCOUNTS],
+    multisig_signers: &[&NoStdAccountInfo],
+    instruction_data: &[u8],
+    signers: PdaSigners,
+) -> ProgramResult {
+    if ACCOUNTS == 0 || ACCOUNTS + multisig_signers.len() > MAX_ACCOUNTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !multisig_signers.is_empty() {
+        account_metas[ACCOUNTS - 1].is_signer = false;
+    }
+
+    const UNINIT: MaybeUninit<AccountMetaC> = MaybeUninit::<AccountMetaC>::uninit();
+    let mut metas = [UNINIT; MAX_ACCOUNTS];
+    let mut account_infos = [accounts[0]; MAX_ACCOUNTS];
+
+    for (index, (meta, account)) in account_metas.into_iter().zip(accounts).enumerate() {
+        metas[index].write(meta);
+        account_infos[index] = account;
+    }
+
+    for (index, signer) in multisig_signers.iter().enumerate() {
+        metas[ACCOUNTS + index].write(signer.to_meta_c_signer());
+        account_infos[ACCOUNTS + index] = signer;
+    }
+
+    let accounts_len = ACCOUNTS + multisig_signers.len();
+
+    let instruction = InstructionC {
+        accounts: metas.as_ptr() as *const AccountMetaC,
+        accounts_len: accounts_len as u64,
+        data: instruction_data.as_ptr(),
+        data_len: instruction_data.len() as u64,
+        program_id,
+    };
+
+    invoke_signed_slice::<MAX_ACCOUNTS>(&instruction, &account_infos[..accounts_len], signers)
+}
+
+#[cfg(test)]
+mod tests {
+    use nostd_entrypoint_invoke::mock::{self, MockAccount};
+    use spl_token::instruction::transfer;
+
+    use super::*;
+    use crate::instructions::Transfer;
+
+    #[test]
+    fn multisig_metas_match_spl_token() {
+        let account = |is_writable, is_signer| {
+            MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &[])
+                .writable(is_writable)
+                .signer(is_signer)
+        };
+
+        let (source, destination) = (account(true, false), account(true, false));
+        // the multisignature account signing the transaction is not a
+        // signer of the instruction
+        let authority = account(false, true);
+        let signers = [account(false, true), account(false, true)];
+
+        mock::clear_invoked_instructions();
+
+        Transfer {
+            source: source.info(),
+            destination: destination.info(),
+            authority: authority.info(),
+            multisig_signers: &[signers[0].info(), signers[1].info()],
+            amount: 10,
+        }
+        .invoke()
+        .unwrap();
+
+        let expected = transfer(
+            &crate::ID,
+            &source.key(),
+            &destination.key(),
+            &authority.key(),
+            &[&signers[0].key(), &signers[1].key()],
+            10,
+        )
+        .unwrap();
+
+        let invoked = mock::take_invoked_instructions();
+        assert_eq!(invoked.len(), 1);
+        assert_eq!(invoked[0].instruction, expected);
+    }
+}
@@ -0,0 +1,270 @@
+use core::ops::Deref;
+
+use nostd_entrypoint_invoke::state::StateRef;
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use super::option_pubkey;
+
+/// Token account state.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountState {
+    /// Account is not yet initialized.
+    Uninitialized,
+
+    /// Account is initialized; the account owner and/or delegate may perform
+    /// permitted operations on this account.
+    Initialized,
+
+    /// Account has been frozen by the mint freeze authority. Neither the
+    /// account owner nor the delegate are able to perform operations on
+    /// this account.
+    Frozen,
+}
+
+/// Zero-copy view of a token account.
+///
+/// The layout matches the 165 bytes of a packed `spl_token::state::Account`.
+#[repr(C)]
+pub struct Account {
+    /// The mint associated with this account.
+    mint: Pubkey,
+
+    /// The owner of this account.
+    owner: Pubkey,
+
+    /// The amount of tokens this account holds.
+    amount: [u8; 8],
+
+    /// Indicates whether the delegate is present or not.
+    delegate_flag: [u8; 4],
+
+    /// If `delegate` is present, the amount authorized by the delegate.
+    delegate: Pubkey,
+
+    /// The account's state.
+    state: u8,
+
+    /// Indicates whether this account represents a native token or not.
+    is_native_flag: [u8; 4],
+
+    /// For native accounts, the rent-exempt reserve.
+    is_native: [u8; 8],
+
+    /// The amount delegated.
+    delegated_amount: [u8; 8],
+
+    /// Indicates whether the close authority is present or not.
+    close_authority_flag: [u8; 4],
+
+    /// Optional authority to close the account.
+    close_authority: Pubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<Account>() == Account::LEN);
+const _: () = assert!(core::mem::align_of::<Account>() == 1);
+
+impl Account {
+    /// Length of the token account data.
+    pub const LEN: usize = 165;
+
+    /// Borrow the data of an initialized token account owned by the token
+    /// program.
+    pub fn from_account_info(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = Account> + '_, ProgramError> {
+        if *account_info.owner() != crate::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let data = account_info
+            .try_borrow_data()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+
+        Self::from_bytes(&data)?;
+
+        Ok(unsafe { StateRef::<_, Account>::new(data) })
+    }
+
+    /// View the bytes of an initialized token account.
+    pub fn from_bytes(data: &[u8]) -> Result<&Account, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let account = unsafe { &*(data.as_ptr() as *const Account) };
+
+        match account.state {
+            0 => Err(ProgramError::UninitializedAccount),
+            1 | 2 => Ok(account),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    #[inline(always)]
+    pub fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    #[inline(always)]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    #[inline(always)]
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<&Pubkey> {
+        option_pubkey(&self.delegate_flag, &self.delegate)
+    }
+
+    #[inline(always)]
+    pub fn state(&self) -> AccountState {
+        match self.state {
+            1 => AccountState::Initialized,
+            2 => AccountState::Frozen,
+            _ => AccountState::Uninitialized,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        self.state == AccountState::Frozen as u8
+    }
+
+    /// Return the rent-exempt reserve of a native account, `None` otherwise.
+    #[inline(always)]
+    pub fn is_native(&self) -> Option<u64> {
+        (self.is_native_flag == [1, 0, 0, 0]).then(|| u64::from_le_bytes(self.is_native))
+    }
+
+    #[inline(always)]
+    pub fn delegated_amount(&self) -> u64 {
+        u64::from_le_bytes(self.delegated_amount)
+    }
+
+    #[inline(always)]
+    pub fn close_authority(&self) -> Option<&Pubkey> {
+        option_pubkey(&self.close_authority_flag, &self.close_authority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::MockAccount;
+    use solana_program::{program_option::COption, program_pack::Pack};
+    use spl_token::state;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+
+    /// Offset of the state byte in a packed account.
+    const STATE_OFFSET: usize = 108;
+
+    fn pack(account: state::Account) -> Vec<u8> {
+        let mut data = vec![0; Account::LEN];
+        state::Account::pack(account, &mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn reads_packed_accounts() {
+        let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (delegate, close_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        for (delegate, is_native, close_authority, spl_state, account_state) in [
+            (
+                COption::Some(delegate),
+                COption::Some(2_039_280),
+                COption::Some(close_authority),
+                state::AccountState::Initialized,
+                AccountState::Initialized,
+            ),
+            (
+                COption::None,
+                COption::None,
+                COption::None,
+                state::AccountState::Frozen,
+                AccountState::Frozen,
+            ),
+        ] {
+            let data = pack(state::Account {
+                mint,
+                owner,
+                amount: 42,
+                delegate,
+                state: spl_state,
+                is_native,
+                delegated_amount: 7,
+                close_authority,
+            });
+            let account = Account::from_bytes(&data).unwrap();
+
+            assert_eq!(*account.mint(), mint);
+            assert_eq!(*account.owner(), owner);
+            assert_eq!(account.amount(), 42);
+            assert_eq!(account.delegate(), Option::from(delegate.as_ref()));
+            assert_eq!(account.state(), account_state);
+            assert_eq!(account.is_frozen(), account_state == AccountState::Frozen);
+            assert_eq!(account.is_native(), Option::from(is_native));
+            assert_eq!(account.delegated_amount(), 7);
+            assert_eq!(
+                account.close_authority(),
+                Option::from(close_authority.as_ref())
+            );
+        }
+    }
+
+    #[test]
+    fn checks_the_state_byte() {
+        let mut data = pack(state::Account {
+            state: state::AccountState::Initialized,
+            ..Default::default()
+        });
+        assert_eq!(data[STATE_OFFSET], 1);
+
+        for (state, result) in [
+            (0, Err(ProgramError::UninitializedAccount)),
+            (1, Ok(AccountState::Initialized)),
+            (2, Ok(AccountState::Frozen)),
+            (3, Err(ProgramError::InvalidAccountData)),
+        ] {
+            data[STATE_OFFSET] = state;
+            assert_eq!(
+                Account::from_bytes(&data).map(|account| account.state()),
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_accounts() {
+        let data = pack(state::Account {
+            state: state::AccountState::Initialized,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            Account::from_bytes(&data[..Account::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let account = MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &data);
+        assert_eq!(
+            Account::from_account_info(account.info()).unwrap().state(),
+            AccountState::Initialized
+        );
+
+        let foreign = MockAccount::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, &data);
+        assert_eq!(
+            Account::from_account_info(foreign.info()).err(),
+            Some(ProgramError::IncorrectProgramId)
+        );
+    }
+}
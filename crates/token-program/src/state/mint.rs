@@ -0,0 +1,176 @@
+use core::ops::Deref;
+
+use nostd_entrypoint_invoke::state::StateRef;
+use solana_nostd_entrypoint::NoStdAccountInfo;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use super::option_pubkey;
+
+/// Zero-copy view of a token mint.
+///
+/// The layout matches the 82 bytes of a packed `spl_token::state::Mint`.
+#[repr(C)]
+pub struct Mint {
+    /// Indicates whether the mint authority is present or not.
+    mint_authority_flag: [u8; 4],
+
+    /// Optional authority used to mint new tokens.
+    mint_authority: Pubkey,
+
+    /// Total supply of tokens.
+    supply: [u8; 8],
+
+    /// Number of base 10 digits to the right of the decimal place.
+    decimals: u8,
+
+    /// Is `true` if this structure has been initialized.
+    is_initialized: u8,
+
+    /// Indicates whether the freeze authority is present or not.
+    freeze_authority_flag: [u8; 4],
+
+    /// Optional authority to freeze token accounts.
+    freeze_authority: Pubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<Mint>() == Mint::LEN);
+const _: () = assert!(core::mem::align_of::<Mint>() == 1);
+
+impl Mint {
+    /// Length of the mint account data.
+    pub const LEN: usize = 82;
+
+    /// Borrow the data of an initialized mint owned by the token program.
+    pub fn from_account_info(
+        account_info: &NoStdAccountInfo,
+    ) -> Result<impl Deref<Target = Mint> + '_, ProgramError> {
+        if *account_info.owner() != crate::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let data = account_info
+            .try_borrow_data()
+            .ok_or(ProgramError::AccountBorrowFailed)?;
+
+        Self::from_bytes(&data)?;
+
+        Ok(unsafe { StateRef::<_, Mint>::new(data) })
+    }
+
+    /// View the bytes of an initialized mint.
+    pub fn from_bytes(data: &[u8]) -> Result<&Mint, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint = unsafe { &*(data.as_ptr() as *const Mint) };
+
+        if !mint.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(mint)
+    }
+
+    #[inline(always)]
+    pub fn mint_authority(&self) -> Option<&Pubkey> {
+        option_pubkey(&self.mint_authority_flag, &self.mint_authority)
+    }
+
+    #[inline(always)]
+    pub fn supply(&self) -> u64 {
+        u64::from_le_bytes(self.supply)
+    }
+
+    #[inline(always)]
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized == 1
+    }
+
+    #[inline(always)]
+    pub fn freeze_authority(&self) -> Option<&Pubkey> {
+        option_pubkey(&self.freeze_authority_flag, &self.freeze_authority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use nostd_entrypoint_invoke::mock::MockAccount;
+    use solana_program::{program_option::COption, program_pack::Pack};
+    use spl_token::state;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+
+    fn pack(mint: state::Mint) -> Vec<u8> {
+        let mut data = vec![0; Mint::LEN];
+        state::Mint::pack(mint, &mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn reads_packed_mints() {
+        let (mint_authority, freeze_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        for (mint_authority, freeze_authority) in [
+            (
+                COption::Some(mint_authority),
+                COption::Some(freeze_authority),
+            ),
+            (COption::None, COption::None),
+        ] {
+            let data = pack(state::Mint {
+                mint_authority,
+                supply: u64::MAX - 1,
+                decimals: 9,
+                is_initialized: true,
+                freeze_authority,
+            });
+            let mint = Mint::from_bytes(&data).unwrap();
+
+            assert_eq!(mint.mint_authority(), Option::from(mint_authority.as_ref()));
+            assert_eq!(mint.supply(), u64::MAX - 1);
+            assert_eq!(mint.decimals(), 9);
+            assert!(mint.is_initialized());
+            assert_eq!(
+                mint.freeze_authority(),
+                Option::from(freeze_authority.as_ref())
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_mints() {
+        let data = pack(state::Mint {
+            is_initialized: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            Mint::from_bytes(&data[..Mint::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Mint::from_bytes(&[0; Mint::LEN]).err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+
+        let mint = MockAccount::new(Pubkey::new_unique(), crate::ID, 0, &data);
+        assert!(Mint::from_account_info(mint.info())
+            .unwrap()
+            .is_initialized());
+
+        let foreign = MockAccount::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, &data);
+        assert_eq!(
+            Mint::from_account_info(foreign.info()).err(),
+            Some(ProgramError::IncorrectProgramId)
+        );
+    }
+}
@@ -0,0 +1,13 @@
+mod account;
+mod mint;
+
+pub use account::*;
+pub use mint::*;
+
+use solana_program::pubkey::Pubkey;
+
+/// Read a `COption<Pubkey>` with its 4-byte tag.
+#[inline(always)]
+fn option_pubkey<'a>(tag: &[u8; 4], value: &'a Pubkey) -> Option<&'a Pubkey> {
+    (*tag == [1, 0, 0, 0]).then_some(value)
+}